@@ -0,0 +1,358 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use quote::quote;
+use syn::parse_macro_input;
+use syn::parse_quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Error;
+use syn::Fields;
+use syn::Ident;
+use syn::LitInt;
+use syn::LitStr;
+use syn::Path;
+
+#[derive(Default)]
+struct ContainerAttributes {
+  version: u32,
+}
+
+enum FieldDefault {
+  Trait,
+  Function(Path),
+}
+
+#[derive(Default)]
+struct FieldAttributes {
+  skip: bool,
+  default: Option<FieldDefault>,
+  since: Option<u32>,
+}
+
+struct ShuttleField {
+  name: String,
+  binding: Ident,
+  member: TokenStream2,
+  ty: syn::Type,
+  attributes: FieldAttributes,
+}
+
+#[proc_macro_derive(Shuttle, attributes(shuttle))]
+pub fn derive_shuttle(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  match expand(input) {
+    Ok(tokens) => tokens.into(),
+    Err(error) => error.to_compile_error().into(),
+  }
+}
+
+fn expand(mut input: DeriveInput) -> Result<TokenStream2, Error> {
+  let container = parse_container_attributes(&input.attrs)?;
+  let type_parameters = input
+    .generics
+    .type_params()
+    .map(|parameter| parameter.ident.clone())
+    .collect::<Vec<_>>();
+  let where_clause = input.generics.make_where_clause();
+  for parameter in type_parameters {
+    where_clause
+      .predicates
+      .push(parse_quote!(#parameter: ::beam::serialization::Shuttle));
+  }
+  let (send, receive) = match &input.data {
+    Data::Struct(data) => {
+      let fields = collect_fields(&data.fields)?;
+      expand_struct(&data.fields, &fields)
+    }
+    Data::Enum(data) => {
+      let mut variants = Vec::new();
+      for variant in &data.variants {
+        variants.push((
+          variant.ident.clone(),
+          variant.fields.clone(),
+          collect_fields(&variant.fields)?,
+        ));
+      }
+      expand_enum(&variants)
+    }
+    Data::Union(_) => {
+      return Err(Error::new(
+        Span::call_site(),
+        "Shuttle can not be derived for unions",
+      ))
+    }
+  };
+  let name = &input.ident;
+  let version = container.version;
+  let (impl_generics, type_generics, where_clause) =
+    input.generics.split_for_impl();
+  Ok(quote! {
+    impl #impl_generics ::beam::serialization::Shuttle for #name #type_generics
+      #where_clause
+    {
+      const VERSION: u32 = #version;
+
+      fn send<__S: ::beam::serialization::Sender + ?Sized>(
+        &self,
+        sender: &mut __S,
+        name: &str,
+      ) {
+        #send
+      }
+
+      fn receive<__R: ::beam::serialization::Receiver + ?Sized>(
+        receiver: &mut __R,
+        name: &str,
+      ) -> ::std::result::Result<
+        Self,
+        ::beam::serialization::SerializationError,
+      > {
+        #receive
+      }
+    }
+  })
+}
+
+fn parse_container_attributes(
+  attributes: &[syn::Attribute],
+) -> Result<ContainerAttributes, Error> {
+  let mut container = ContainerAttributes::default();
+  for attribute in attributes {
+    if !attribute.path().is_ident("shuttle") {
+      continue;
+    }
+    attribute.parse_nested_meta(|meta| {
+      if meta.path.is_ident("version") {
+        container.version = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+        Ok(())
+      } else {
+        Err(meta.error("unsupported shuttle attribute"))
+      }
+    })?;
+  }
+  Ok(container)
+}
+
+fn parse_field_attributes(
+  attributes: &[syn::Attribute],
+) -> Result<FieldAttributes, Error> {
+  let mut field = FieldAttributes::default();
+  for attribute in attributes {
+    if !attribute.path().is_ident("shuttle") {
+      continue;
+    }
+    attribute.parse_nested_meta(|meta| {
+      if meta.path.is_ident("skip") {
+        field.skip = true;
+        Ok(())
+      } else if meta.path.is_ident("default") {
+        if meta.input.peek(syn::Token![=]) {
+          let path = meta.value()?.parse::<LitStr>()?.parse::<Path>()?;
+          field.default = Some(FieldDefault::Function(path));
+        } else {
+          field.default = Some(FieldDefault::Trait);
+        }
+        Ok(())
+      } else if meta.path.is_ident("since") {
+        field.since = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+        Ok(())
+      } else {
+        Err(meta.error("unsupported shuttle attribute"))
+      }
+    })?;
+  }
+  Ok(field)
+}
+
+fn collect_fields(fields: &Fields) -> Result<Vec<ShuttleField>, Error> {
+  let mut result = Vec::new();
+  for (index, field) in fields.iter().enumerate() {
+    let (name, member) = match &field.ident {
+      Some(ident) => (ident.to_string(), quote!(#ident)),
+      None => {
+        let index = syn::Index::from(index);
+        (index.index.to_string(), quote!(#index))
+      }
+    };
+    result.push(ShuttleField {
+      name,
+      binding: format_ident!("__field{}", index),
+      member,
+      ty: field.ty.clone(),
+      attributes: parse_field_attributes(&field.attrs)?,
+    });
+  }
+  Ok(result)
+}
+
+fn default_value(field: &ShuttleField) -> TokenStream2 {
+  match &field.attributes.default {
+    Some(FieldDefault::Function(path)) => quote!(#path()),
+    _ => quote!(::std::default::Default::default()),
+  }
+}
+
+fn send_fields(fields: &[ShuttleField]) -> TokenStream2 {
+  let sends =
+    fields
+      .iter()
+      .filter(|field| !field.attributes.skip)
+      .map(|field| {
+        let binding = &field.binding;
+        let name = &field.name;
+        quote! {
+          ::beam::serialization::Shuttle::send(#binding, sender, #name);
+        }
+      });
+  quote!(#(#sends)*)
+}
+
+fn receive_fields(fields: &[ShuttleField]) -> TokenStream2 {
+  let receives = fields.iter().map(|field| {
+    let binding = &field.binding;
+    let name = &field.name;
+    let ty = &field.ty;
+    let default = default_value(field);
+    let receive = quote! {
+      <#ty as ::beam::serialization::Shuttle>::receive(receiver, #name)?
+    };
+    if field.attributes.skip {
+      quote!(let #binding: #ty = #default;)
+    } else if let Some(since) = field.attributes.since {
+      quote! {
+        let #binding: #ty = if __version >= #since {
+          #receive
+        } else {
+          #default
+        };
+      }
+    } else {
+      quote!(let #binding: #ty = #receive;)
+    }
+  });
+  quote!(#(#receives)*)
+}
+
+fn construct(
+  path: TokenStream2,
+  shape: &Fields,
+  fields: &[ShuttleField],
+) -> TokenStream2 {
+  let members = fields.iter().map(|field| &field.member);
+  let bindings = fields.iter().map(|field| &field.binding);
+  match shape {
+    Fields::Named(_) => quote!(#path { #(#members: #bindings),* }),
+    Fields::Unnamed(_) => quote!(#path ( #(#bindings),* )),
+    Fields::Unit => quote!(#path),
+  }
+}
+
+fn expand_struct(
+  shape: &Fields,
+  fields: &[ShuttleField],
+) -> (TokenStream2, TokenStream2) {
+  let members = fields.iter().map(|field| &field.member);
+  let bindings = fields.iter().map(|field| &field.binding);
+  let send_fields = send_fields(fields);
+  let receive_fields = receive_fields(fields);
+  let value = construct(quote!(Self), shape, fields);
+  let send = quote! {
+    #(let #bindings = &self.#members;)*
+    sender.start_structure(
+      name,
+      <Self as ::beam::serialization::Shuttle>::VERSION,
+    );
+    #send_fields
+    sender.end_structure();
+  };
+  let receive = quote! {
+    let __version = receiver.start_structure(name)?;
+    #receive_fields
+    receiver.end_structure()?;
+    ::std::result::Result::Ok(#value)
+  };
+  (send, receive)
+}
+
+fn expand_enum(
+  variants: &[(Ident, Fields, Vec<ShuttleField>)],
+) -> (TokenStream2, TokenStream2) {
+  let is_unit = variants
+    .iter()
+    .all(|(_, shape, _)| matches!(shape, Fields::Unit));
+  let indices = (0..variants.len() as u32).collect::<Vec<_>>();
+  if is_unit {
+    let names = variants.iter().map(|(name, _, _)| name).collect::<Vec<_>>();
+    let send = quote! {
+      let __variant: u32 = match self {
+        #(Self::#names => Self::#names as u32,)*
+      };
+      sender.send_u32(name, __variant);
+    };
+    let receive = quote! {
+      match receiver.receive_u32(name)? {
+        #(__variant if __variant == Self::#names as u32 => {
+          ::std::result::Result::Ok(Self::#names)
+        })*
+        _ => ::std::result::Result::Err(
+          ::beam::serialization::SerializationError::new("Invalid variant."),
+        ),
+      }
+    };
+    return (send, receive);
+  }
+  let send_arms =
+    variants
+      .iter()
+      .zip(&indices)
+      .map(|((variant, shape, fields), index)| {
+        let pattern = construct(quote!(Self::#variant), shape, fields);
+        let send_fields = send_fields(fields);
+        quote! {
+          #pattern => {
+            sender.send_u32("variant", #index);
+            #send_fields
+          }
+        }
+      });
+  let receive_arms =
+    variants
+      .iter()
+      .zip(&indices)
+      .map(|((variant, shape, fields), index)| {
+        let receive_fields = receive_fields(fields);
+        let value = construct(quote!(Self::#variant), shape, fields);
+        quote! {
+          #index => {
+            #receive_fields
+            #value
+          }
+        }
+      });
+  let send = quote! {
+    sender.start_structure(
+      name,
+      <Self as ::beam::serialization::Shuttle>::VERSION,
+    );
+    match self {
+      #(#send_arms)*
+    }
+    sender.end_structure();
+  };
+  let receive = quote! {
+    let __version = receiver.start_structure(name)?;
+    let __value = match receiver.receive_u32("variant")? {
+      #(#receive_arms)*
+      _ => {
+        return ::std::result::Result::Err(
+          ::beam::serialization::SerializationError::new("Invalid variant."),
+        )
+      }
+    };
+    receiver.end_structure()?;
+    ::std::result::Result::Ok(__value)
+  };
+  (send, receive)
+}
@@ -0,0 +1,44 @@
+use crate::codecs::codec_type::*;
+use crate::codecs::coded_reader::*;
+use crate::codecs::coded_writer::*;
+use crate::io::*;
+
+pub fn negotiate_connection_codecs<R: Reader, W: Writer>(
+  reader: &CodedReader<R>,
+  writer: &CodedWriter<W>,
+  preferences: &[CodecType],
+) -> std::io::Result<(CodecType, CodecType)> {
+  let names = preferences
+    .iter()
+    .map(|preference| preference.name())
+    .collect::<Vec<_>>()
+    .join(",");
+  writer.write(&Buffer::from(names.as_str()))?;
+  let mut message = Buffer::new();
+  if reader.read_message(&mut message)?.is_none() {
+    return Err(std::io::ErrorKind::UnexpectedEof.into());
+  }
+  let peer_names = std::str::from_utf8(&message).map_err(|_| {
+    std::io::Error::new(
+      std::io::ErrorKind::InvalidData,
+      "Invalid codec negotiation message.",
+    )
+  })?;
+  let peer_names = peer_names
+    .split(',')
+    .filter(|name| !name.is_empty())
+    .collect::<Vec<_>>();
+  let peer_preferences = peer_names
+    .iter()
+    .filter_map(|name| CodecType::from_name(name))
+    .collect::<Vec<_>>();
+  let supported = preferences
+    .iter()
+    .map(|preference| preference.name())
+    .collect::<Vec<_>>();
+  let encoding = negotiate_codec(preferences, &peer_names);
+  let decoding = negotiate_codec(&peer_preferences, &supported);
+  writer.set_encoder(encoding.encoder());
+  reader.set_decoder(decoding.decoder());
+  Ok((encoding, decoding))
+}
@@ -0,0 +1,59 @@
+use crate::codecs::decoder::*;
+use crate::codecs::encoder::*;
+use crate::codecs::null_decoder::*;
+use crate::codecs::null_encoder::*;
+use crate::codecs::zlib_decoder::*;
+use crate::codecs::zlib_encoder::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CodecType {
+  #[default]
+  Null,
+  Zlib,
+}
+
+impl CodecType {
+  pub const ALL: [CodecType; 2] = [CodecType::Zlib, CodecType::Null];
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      CodecType::Null => "null",
+      CodecType::Zlib => "zlib",
+    }
+  }
+
+  pub fn from_name(name: &str) -> Option<Self> {
+    CodecType::ALL
+      .into_iter()
+      .find(|codec_type| codec_type.name() == name)
+  }
+
+  pub fn encoder(&self) -> Box<dyn Encoder> {
+    match self {
+      CodecType::Null => Box::new(NullEncoder),
+      CodecType::Zlib => Box::new(ZlibEncoder::new()),
+    }
+  }
+
+  pub fn decoder(&self) -> Box<dyn Decoder> {
+    match self {
+      CodecType::Null => Box::new(NullDecoder),
+      CodecType::Zlib => Box::new(ZlibDecoder::new()),
+    }
+  }
+}
+
+pub fn negotiate_codec<S: AsRef<str>>(
+  preferences: &[CodecType],
+  supported: &[S],
+) -> CodecType {
+  preferences
+    .iter()
+    .copied()
+    .find(|preference| {
+      supported
+        .iter()
+        .any(|name| name.as_ref() == preference.name())
+    })
+    .unwrap_or_default()
+}
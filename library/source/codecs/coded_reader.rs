@@ -0,0 +1,117 @@
+use std::sync::RwLock;
+
+use crate::codecs::decoder::*;
+use crate::io::*;
+use crate::threading::Mutex;
+
+pub struct CodedReader<R: Reader> {
+  source: SizeDeclarativeReader<R>,
+  decoder: RwLock<Box<dyn Decoder>>,
+  reads: Mutex<()>,
+  buffer: std::sync::Mutex<Buffer>,
+}
+
+impl<R: Reader> CodedReader<R> {
+  pub fn new(source: R, decoder: impl Decoder + 'static) -> Self {
+    CodedReader::with_source(SizeDeclarativeReader::new(source), decoder)
+  }
+
+  pub fn with_max_message_size(
+    source: R,
+    decoder: impl Decoder + 'static,
+    max_message_size: usize,
+  ) -> Self {
+    CodedReader::with_source(
+      SizeDeclarativeReader::with_max_message_size(source, max_message_size),
+      decoder,
+    )
+  }
+
+  fn with_source(
+    source: SizeDeclarativeReader<R>,
+    decoder: impl Decoder + 'static,
+  ) -> Self {
+    CodedReader {
+      source,
+      decoder: RwLock::new(Box::new(decoder)),
+      reads: Mutex::new(()),
+      buffer: std::sync::Mutex::new(Buffer::new()),
+    }
+  }
+
+  pub fn source(&self) -> &R {
+    self.source.source()
+  }
+
+  pub fn set_decoder(&self, decoder: Box<dyn Decoder>) {
+    *self.decoder.write().unwrap() = decoder;
+  }
+
+  pub fn read_message(
+    &self,
+    buffer: &mut Buffer,
+  ) -> std::io::Result<Option<usize>> {
+    let _reads = self.reads.lock();
+    let decoded = std::mem::take(&mut *self.buffer.lock().unwrap());
+    if !decoded.is_empty() {
+      buffer.append(&decoded);
+      return Ok(Some(decoded.len()));
+    }
+    self.decode_message(buffer)
+  }
+
+  fn decode_message(
+    &self,
+    buffer: &mut Buffer,
+  ) -> std::io::Result<Option<usize>> {
+    let mut encoded = Buffer::new();
+    if self.source.read_message(&mut encoded)?.is_none() {
+      return Ok(None);
+    }
+    let max_size = self.source.max_message_size();
+    let size = self
+      .decoder
+      .read()
+      .unwrap()
+      .decode(&encoded, buffer, max_size)?;
+    Ok(Some(size))
+  }
+}
+
+impl<R: Reader> Reader for CodedReader<R> {
+  fn poll(&self) -> std::io::Result<bool> {
+    if !self.buffer.lock().unwrap().is_empty() {
+      return Ok(true);
+    }
+    self.source.poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    let _reads = self.reads.lock();
+    let mut decoded = std::mem::take(&mut *self.buffer.lock().unwrap());
+    while decoded.is_empty() {
+      if self.decode_message(&mut decoded)?.is_none() {
+        return Ok(0);
+      }
+    }
+    let count = size.min(decoded.len());
+    buffer.append(&decoded[..count]);
+    decoded.shrink_front(count);
+    *self.buffer.lock().unwrap() = decoded;
+    Ok(count)
+  }
+
+  fn read(&self, buffer: &mut Buffer) -> std::io::Result<usize> {
+    loop {
+      match self.read_message(buffer)? {
+        Some(0) => continue,
+        Some(size) => return Ok(size),
+        None => return Ok(0),
+      }
+    }
+  }
+}
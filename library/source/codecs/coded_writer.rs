@@ -0,0 +1,34 @@
+use std::sync::RwLock;
+
+use crate::codecs::encoder::*;
+use crate::io::*;
+
+pub struct CodedWriter<W: Writer> {
+  destination: SizeDeclarativeWriter<W>,
+  encoder: RwLock<Box<dyn Encoder>>,
+}
+
+impl<W: Writer> CodedWriter<W> {
+  pub fn new(destination: W, encoder: impl Encoder + 'static) -> Self {
+    CodedWriter {
+      destination: SizeDeclarativeWriter::new(destination),
+      encoder: RwLock::new(Box::new(encoder)),
+    }
+  }
+
+  pub fn destination(&self) -> &W {
+    self.destination.destination()
+  }
+
+  pub fn set_encoder(&self, encoder: Box<dyn Encoder>) {
+    *self.encoder.write().unwrap() = encoder;
+  }
+}
+
+impl<W: Writer> Writer for CodedWriter<W> {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    let mut encoded = Buffer::new();
+    self.encoder.read().unwrap().encode(data, &mut encoded)?;
+    self.destination.write(&encoded)
+  }
+}
@@ -0,0 +1,10 @@
+use crate::io::*;
+
+pub trait Decoder: Send + Sync {
+  fn decode(
+    &self,
+    source: &Buffer,
+    destination: &mut Buffer,
+    max_size: usize,
+  ) -> std::io::Result<usize>;
+}
@@ -0,0 +1,9 @@
+use crate::io::*;
+
+pub trait Encoder: Send + Sync {
+  fn encode(
+    &self,
+    source: &Buffer,
+    destination: &mut Buffer,
+  ) -> std::io::Result<usize>;
+}
@@ -0,0 +1,21 @@
+mod codec_negotiation;
+mod codec_type;
+mod coded_reader;
+mod coded_writer;
+mod decoder;
+mod encoder;
+mod null_decoder;
+mod null_encoder;
+mod zlib_decoder;
+mod zlib_encoder;
+
+pub use codec_negotiation::*;
+pub use codec_type::*;
+pub use coded_reader::*;
+pub use coded_writer::*;
+pub use decoder::*;
+pub use encoder::*;
+pub use null_decoder::*;
+pub use null_encoder::*;
+pub use zlib_decoder::*;
+pub use zlib_encoder::*;
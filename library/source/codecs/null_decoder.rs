@@ -0,0 +1,23 @@
+use crate::codecs::decoder::*;
+use crate::io::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullDecoder;
+
+impl Decoder for NullDecoder {
+  fn decode(
+    &self,
+    source: &Buffer,
+    destination: &mut Buffer,
+    max_size: usize,
+  ) -> std::io::Result<usize> {
+    if source.len() > max_size {
+      return Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "Decoded message exceeds the maximum size.",
+      ));
+    }
+    destination.append(source);
+    Ok(source.len())
+  }
+}
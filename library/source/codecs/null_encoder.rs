@@ -0,0 +1,16 @@
+use crate::codecs::encoder::*;
+use crate::io::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullEncoder;
+
+impl Encoder for NullEncoder {
+  fn encode(
+    &self,
+    source: &Buffer,
+    destination: &mut Buffer,
+  ) -> std::io::Result<usize> {
+    destination.append(source);
+    Ok(source.len())
+  }
+}
@@ -0,0 +1,42 @@
+use std::io::Read;
+
+use flate2::read::ZlibDecoder as FlateDecoder;
+
+use crate::codecs::decoder::*;
+use crate::io::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ZlibDecoder;
+
+impl ZlibDecoder {
+  pub fn new() -> Self {
+    ZlibDecoder
+  }
+}
+
+impl Decoder for ZlibDecoder {
+  fn decode(
+    &self,
+    source: &Buffer,
+    destination: &mut Buffer,
+    max_size: usize,
+  ) -> std::io::Result<usize> {
+    let limit = (max_size as u64).saturating_add(1);
+    let mut decoder = FlateDecoder::new(source.as_slice()).take(limit);
+    let mut size = 0;
+    loop {
+      let count = destination.read_from(&mut decoder, 64 * 1024)?;
+      if count == 0 {
+        return Ok(size);
+      }
+      size += count;
+      if size > max_size {
+        destination.shrink(size);
+        return Err(std::io::Error::new(
+          std::io::ErrorKind::InvalidData,
+          "Decoded message exceeds the maximum size.",
+        ));
+      }
+    }
+  }
+}
@@ -0,0 +1,49 @@
+use std::io::Write;
+
+use flate2::write::ZlibEncoder as FlateEncoder;
+use flate2::Compression;
+
+use crate::codecs::encoder::*;
+use crate::io::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ZlibEncoder {
+  level: u32,
+}
+
+impl ZlibEncoder {
+  pub fn new() -> Self {
+    ZlibEncoder::with_level(Compression::default().level())
+  }
+
+  pub fn with_level(level: u32) -> Self {
+    ZlibEncoder {
+      level: level.min(9),
+    }
+  }
+
+  pub fn level(&self) -> u32 {
+    self.level
+  }
+}
+
+impl Default for ZlibEncoder {
+  fn default() -> Self {
+    ZlibEncoder::new()
+  }
+}
+
+impl Encoder for ZlibEncoder {
+  fn encode(
+    &self,
+    source: &Buffer,
+    destination: &mut Buffer,
+  ) -> std::io::Result<usize> {
+    let size = destination.len();
+    let mut encoder =
+      FlateEncoder::new(&mut *destination, Compression::new(self.level));
+    encoder.write_all(source)?;
+    encoder.finish()?;
+    Ok(destination.len() - size)
+  }
+}
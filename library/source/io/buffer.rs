@@ -0,0 +1,307 @@
+use std::fmt;
+use std::io::Read;
+use std::io::Write;
+use std::mem::MaybeUninit;
+use std::ops::Bound;
+use std::ops::Deref;
+use std::ops::RangeBounds;
+use std::sync::Arc;
+
+use crate::io::buffer_reader::*;
+use crate::io::endianness::*;
+
+#[derive(Clone, Default)]
+pub struct Buffer {
+  data: Arc<Vec<u8>>,
+  offset: usize,
+  length: usize,
+}
+
+impl Buffer {
+  pub fn new() -> Self {
+    Buffer::default()
+  }
+
+  pub fn with_capacity(capacity: usize) -> Self {
+    Buffer::from(Vec::with_capacity(capacity))
+  }
+
+  pub fn len(&self) -> usize {
+    self.length
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.length == 0
+  }
+
+  pub fn capacity(&self) -> usize {
+    if Arc::strong_count(&self.data) == 1 {
+      self.data.capacity() - self.offset
+    } else {
+      self.length
+    }
+  }
+
+  pub fn is_shared(&self) -> bool {
+    Arc::strong_count(&self.data) != 1
+  }
+
+  pub fn as_slice(&self) -> &[u8] {
+    &self.data[self.offset..self.offset + self.length]
+  }
+
+  pub fn as_mut_slice(&mut self) -> &mut [u8] {
+    let length = self.length;
+    let (data, offset) = self.make_mut(0);
+    &mut data[offset..offset + length]
+  }
+
+  pub fn slice(&self, range: impl RangeBounds<usize>) -> Buffer {
+    let start = match range.start_bound() {
+      Bound::Included(start) => *start,
+      Bound::Excluded(start) => start + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(end) => end + 1,
+      Bound::Excluded(end) => *end,
+      Bound::Unbounded => self.length,
+    };
+    assert!(
+      start <= end && end <= self.length,
+      "Slice {}..{} out of range for buffer of length {}.",
+      start,
+      end,
+      self.length
+    );
+    Buffer {
+      data: self.data.clone(),
+      offset: self.offset + start,
+      length: end - start,
+    }
+  }
+
+  pub fn reserve(&mut self, size: usize) {
+    self.make_mut(size).0.reserve(size);
+  }
+
+  pub fn grow(&mut self, size: usize) {
+    let length = self.length + size;
+    self.resize(length);
+  }
+
+  pub fn resize(&mut self, size: usize) {
+    let (data, offset) = self.make_mut(size.saturating_sub(self.length));
+    data.resize(offset + size, 0);
+    self.length = size;
+  }
+
+  pub fn shrink(&mut self, size: usize) {
+    self.length -= size.min(self.length);
+  }
+
+  pub fn shrink_front(&mut self, size: usize) {
+    let size = size.min(self.length);
+    self.offset += size;
+    self.length -= size;
+  }
+
+  pub fn shrink_to_fit(&mut self) {
+    let (data, offset) = self.make_mut(0);
+    data.drain(..offset);
+    data.shrink_to_fit();
+    self.offset = 0;
+  }
+
+  pub fn clear(&mut self) {
+    if let Some(data) = Arc::get_mut(&mut self.data) {
+      data.clear();
+    } else {
+      self.data = Arc::default();
+    }
+    self.offset = 0;
+    self.length = 0;
+  }
+
+  pub fn append(&mut self, bytes: &[u8]) {
+    self.make_mut(bytes.len()).0.extend_from_slice(bytes);
+    self.length += bytes.len();
+  }
+
+  pub fn write_at(&mut self, index: usize, bytes: &[u8]) {
+    let end = index + bytes.len();
+    if end > self.length {
+      self.resize(end);
+    }
+    self.as_mut_slice()[index..end].copy_from_slice(bytes);
+  }
+
+  pub fn get<T: BufferValue>(&self, index: usize) -> Option<T> {
+    self.get_with(index, Endianness::Little)
+  }
+
+  pub fn get_with<T: BufferValue>(
+    &self,
+    index: usize,
+    endianness: Endianness,
+  ) -> Option<T> {
+    let bytes = self.as_slice().get(index..index.checked_add(T::SIZE)?)?;
+    Some(T::decode(bytes, endianness))
+  }
+
+  pub fn put<T: BufferValue>(&mut self, index: usize, value: T) {
+    self.put_with(index, value, Endianness::Little);
+  }
+
+  pub fn put_with<T: BufferValue>(
+    &mut self,
+    index: usize,
+    value: T,
+    endianness: Endianness,
+  ) {
+    let end = index + T::SIZE;
+    if end > self.length {
+      self.resize(end);
+    }
+    value.encode(&mut self.as_mut_slice()[index..end], endianness);
+  }
+
+  pub fn push<T: BufferValue>(&mut self, value: T) {
+    self.push_with(value, Endianness::Little);
+  }
+
+  pub fn push_with<T: BufferValue>(
+    &mut self,
+    value: T,
+    endianness: Endianness,
+  ) {
+    let index = self.length;
+    self.put_with(index, value, endianness);
+  }
+
+  pub fn read_from<R: Read + ?Sized>(
+    &mut self,
+    reader: &mut R,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    let index = self.length;
+    self.grow(size);
+    let result = reader.read(&mut self.as_mut_slice()[index..]);
+    let count = *result.as_ref().unwrap_or(&0);
+    self.shrink(size - count);
+    result
+  }
+
+  pub fn write_to<W: Write + ?Sized>(
+    &self,
+    writer: &mut W,
+  ) -> std::io::Result<()> {
+    writer.write_all(self.as_slice())
+  }
+
+  pub(crate) fn spare_capacity(
+    &mut self,
+    size: usize,
+  ) -> &mut [MaybeUninit<u8>] {
+    let (data, _) = self.make_mut(size);
+    data.reserve(size);
+    &mut data.spare_capacity_mut()[..size]
+  }
+
+  pub(crate) unsafe fn commit(&mut self, count: usize) {
+    let data = Arc::get_mut(&mut self.data).unwrap();
+    data.set_len(data.len() + count);
+    self.length += count;
+  }
+
+  pub fn reader(&self) -> BufferReader {
+    BufferReader::new(self.clone())
+  }
+
+  pub fn into_vec(self) -> Vec<u8> {
+    if self.offset == 0 && self.length == self.data.len() {
+      Arc::try_unwrap(self.data).unwrap_or_else(|data| data.to_vec())
+    } else {
+      self.as_slice().to_vec()
+    }
+  }
+
+  fn make_mut(&mut self, additional: usize) -> (&mut Vec<u8>, usize) {
+    let end = self.offset + self.length;
+    if let Some(data) = Arc::get_mut(&mut self.data) {
+      data.truncate(end);
+      if self.offset != 0 && data.capacity() - end < additional {
+        data.drain(..self.offset);
+        self.offset = 0;
+      }
+    } else {
+      let mut data = Vec::with_capacity(self.length + additional);
+      data.extend_from_slice(self.as_slice());
+      self.data = Arc::new(data);
+      self.offset = 0;
+    }
+    (Arc::get_mut(&mut self.data).unwrap(), self.offset)
+  }
+}
+
+impl Deref for Buffer {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    self.as_slice()
+  }
+}
+
+impl AsRef<[u8]> for Buffer {
+  fn as_ref(&self) -> &[u8] {
+    self.as_slice()
+  }
+}
+
+impl From<Vec<u8>> for Buffer {
+  fn from(data: Vec<u8>) -> Self {
+    let length = data.len();
+    Buffer {
+      data: Arc::new(data),
+      offset: 0,
+      length,
+    }
+  }
+}
+
+impl From<&[u8]> for Buffer {
+  fn from(data: &[u8]) -> Self {
+    Buffer::from(data.to_vec())
+  }
+}
+
+impl From<&str> for Buffer {
+  fn from(data: &str) -> Self {
+    Buffer::from(data.as_bytes())
+  }
+}
+
+impl PartialEq for Buffer {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_slice() == other.as_slice()
+  }
+}
+
+impl Eq for Buffer {}
+
+impl fmt::Debug for Buffer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("Buffer").field(&self.as_slice()).finish()
+  }
+}
+
+impl Write for Buffer {
+  fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+    self.append(bytes);
+    Ok(bytes.len())
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
@@ -0,0 +1,81 @@
+use std::io::BufRead;
+use std::io::Read;
+
+use crate::io::buffer::*;
+use crate::io::endianness::*;
+
+#[derive(Clone, Debug)]
+pub struct BufferReader {
+  buffer: Buffer,
+  position: usize,
+}
+
+impl BufferReader {
+  pub fn new(buffer: Buffer) -> Self {
+    BufferReader {
+      buffer,
+      position: 0,
+    }
+  }
+
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  pub fn remaining(&self) -> usize {
+    self.buffer.len() - self.position
+  }
+
+  pub fn buffer(&self) -> &Buffer {
+    &self.buffer
+  }
+
+  pub fn into_buffer(self) -> Buffer {
+    self.buffer
+  }
+
+  pub fn read_value<T: BufferValue>(&mut self) -> std::io::Result<T> {
+    self.read_value_with(Endianness::Little)
+  }
+
+  pub fn read_value_with<T: BufferValue>(
+    &mut self,
+    endianness: Endianness,
+  ) -> std::io::Result<T> {
+    let value = self
+      .buffer
+      .get_with(self.position, endianness)
+      .ok_or(std::io::ErrorKind::UnexpectedEof)?;
+    self.position += T::SIZE;
+    Ok(value)
+  }
+
+  pub fn read_buffer(&mut self, size: usize) -> std::io::Result<Buffer> {
+    if size > self.remaining() {
+      return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    let buffer = self.buffer.slice(self.position..self.position + size);
+    self.position += size;
+    Ok(buffer)
+  }
+}
+
+impl Read for BufferReader {
+  fn read(&mut self, bytes: &mut [u8]) -> std::io::Result<usize> {
+    let count = bytes.len().min(self.remaining());
+    bytes[..count]
+      .copy_from_slice(&self.buffer[self.position..self.position + count]);
+    self.position += count;
+    Ok(count)
+  }
+}
+
+impl BufRead for BufferReader {
+  fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+    Ok(&self.buffer[self.position..])
+  }
+
+  fn consume(&mut self, amount: usize) {
+    self.position = (self.position + amount).min(self.buffer.len());
+  }
+}
@@ -0,0 +1,49 @@
+use std::io::Write;
+
+use crate::io::buffer::*;
+use crate::io::endianness::*;
+
+pub struct BufferWriter<'a> {
+  buffer: &'a mut Buffer,
+  position: usize,
+}
+
+impl<'a> BufferWriter<'a> {
+  pub fn new(buffer: &'a mut Buffer) -> Self {
+    let position = buffer.len();
+    BufferWriter { buffer, position }
+  }
+
+  pub fn at(buffer: &'a mut Buffer, position: usize) -> Self {
+    BufferWriter { buffer, position }
+  }
+
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  pub fn write_value<T: BufferValue>(&mut self, value: T) {
+    self.write_value_with(value, Endianness::Little);
+  }
+
+  pub fn write_value_with<T: BufferValue>(
+    &mut self,
+    value: T,
+    endianness: Endianness,
+  ) {
+    self.buffer.put_with(self.position, value, endianness);
+    self.position += T::SIZE;
+  }
+}
+
+impl Write for BufferWriter<'_> {
+  fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+    self.buffer.write_at(self.position, bytes);
+    self.position += bytes.len();
+    Ok(bytes.len())
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    Ok(())
+  }
+}
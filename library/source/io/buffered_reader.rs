@@ -0,0 +1,58 @@
+use crate::io::buffer::*;
+use crate::io::reader::*;
+use crate::threading::Mutex;
+
+pub struct BufferedReader<R: Reader> {
+  source: R,
+  read_size: usize,
+  reads: Mutex<()>,
+  buffer: std::sync::Mutex<Buffer>,
+}
+
+impl<R: Reader> BufferedReader<R> {
+  pub fn new(source: R) -> Self {
+    BufferedReader::with_read_size(source, DEFAULT_READ_SIZE)
+  }
+
+  pub fn with_read_size(source: R, read_size: usize) -> Self {
+    BufferedReader {
+      source,
+      read_size,
+      reads: Mutex::new(()),
+      buffer: std::sync::Mutex::new(Buffer::new()),
+    }
+  }
+
+  pub fn source(&self) -> &R {
+    &self.source
+  }
+}
+
+impl<R: Reader> Reader for BufferedReader<R> {
+  fn poll(&self) -> std::io::Result<bool> {
+    if !self.buffer.lock().unwrap().is_empty() {
+      return Ok(true);
+    }
+    self.source.poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    let _reads = self.reads.lock();
+    let mut read_ahead = std::mem::take(&mut *self.buffer.lock().unwrap());
+    if read_ahead.is_empty() {
+      let read_size = self.read_size.max(size);
+      if self.source.read_size(&mut read_ahead, read_size)? == 0 {
+        return Ok(0);
+      }
+    }
+    let count = size.min(read_ahead.len());
+    buffer.append(&read_ahead[..count]);
+    read_ahead.shrink_front(count);
+    *self.buffer.lock().unwrap() = read_ahead;
+    Ok(count)
+  }
+}
@@ -0,0 +1,19 @@
+use crate::io::connection::*;
+use crate::io::identifier::*;
+use crate::io::reader::*;
+use crate::io::writer::*;
+
+pub trait Channel: Send + Sync {
+  type Identifier: Identifier;
+  type Connection: Connection;
+  type Reader: Reader;
+  type Writer: Writer;
+
+  fn identifier(&self) -> &Self::Identifier;
+
+  fn connection(&self) -> &Self::Connection;
+
+  fn reader(&self) -> &Self::Reader;
+
+  fn writer(&self) -> &Self::Writer;
+}
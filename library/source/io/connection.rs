@@ -0,0 +1,3 @@
+pub trait Connection: Send + Sync {
+  fn close(&self) -> std::io::Result<()>;
+}
@@ -0,0 +1,52 @@
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Endianness {
+  #[default]
+  Little,
+  Big,
+}
+
+impl Endianness {
+  pub const fn native() -> Self {
+    if cfg!(target_endian = "big") {
+      Endianness::Big
+    } else {
+      Endianness::Little
+    }
+  }
+}
+
+pub trait BufferValue: Copy {
+  const SIZE: usize;
+
+  fn decode(bytes: &[u8], endianness: Endianness) -> Self;
+
+  fn encode(self, bytes: &mut [u8], endianness: Endianness);
+}
+
+macro_rules! impl_buffer_value {
+  ($($type:ty),*) => {
+    $(
+      impl BufferValue for $type {
+        const SIZE: usize = std::mem::size_of::<$type>();
+
+        fn decode(bytes: &[u8], endianness: Endianness) -> Self {
+          let bytes = bytes[..Self::SIZE].try_into().unwrap();
+          match endianness {
+            Endianness::Little => <$type>::from_le_bytes(bytes),
+            Endianness::Big => <$type>::from_be_bytes(bytes),
+          }
+        }
+
+        fn encode(self, bytes: &mut [u8], endianness: Endianness) {
+          let encoded = match endianness {
+            Endianness::Little => self.to_le_bytes(),
+            Endianness::Big => self.to_be_bytes(),
+          };
+          bytes[..Self::SIZE].copy_from_slice(&encoded);
+        }
+      }
+    )*
+  };
+}
+
+impl_buffer_value!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
@@ -0,0 +1,12 @@
+use std::fmt;
+use std::hash::Hash;
+
+pub trait Identifier:
+  Clone + fmt::Debug + fmt::Display + Eq + Hash + Send + Sync
+{
+}
+
+impl<T: Clone + fmt::Debug + fmt::Display + Eq + Hash + Send + Sync> Identifier
+  for T
+{
+}
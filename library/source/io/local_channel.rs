@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::io::channel::*;
+use crate::io::connection::*;
+use crate::io::local_connection::*;
+use crate::io::local_pipe::*;
+use crate::io::local_reader::*;
+use crate::io::local_server_connection::*;
+use crate::io::local_writer::*;
+
+pub struct LocalChannel {
+  identifier: String,
+  connection: LocalConnection,
+  reader: LocalReader,
+  writer: LocalWriter,
+}
+
+pub type LocalClientChannel = LocalChannel;
+
+impl LocalChannel {
+  pub fn connect(name: &str) -> std::io::Result<Self> {
+    connect_local(name)
+  }
+
+  pub(crate) fn pair(
+    client_identifier: String,
+    server_identifier: String,
+  ) -> (LocalChannel, LocalChannel) {
+    let upstream = Arc::new(LocalPipe::new());
+    let downstream = Arc::new(LocalPipe::new());
+    let client = LocalChannel {
+      identifier: client_identifier,
+      connection: LocalConnection::new(downstream.clone(), upstream.clone()),
+      reader: LocalReader::new(downstream.clone()),
+      writer: LocalWriter::new(upstream.clone()),
+    };
+    let server = LocalChannel {
+      identifier: server_identifier,
+      connection: LocalConnection::new(upstream.clone(), downstream.clone()),
+      reader: LocalReader::new(upstream),
+      writer: LocalWriter::new(downstream),
+    };
+    (client, server)
+  }
+}
+
+impl Channel for LocalChannel {
+  type Identifier = String;
+  type Connection = LocalConnection;
+  type Reader = LocalReader;
+  type Writer = LocalWriter;
+
+  fn identifier(&self) -> &String {
+    &self.identifier
+  }
+
+  fn connection(&self) -> &LocalConnection {
+    &self.connection
+  }
+
+  fn reader(&self) -> &LocalReader {
+    &self.reader
+  }
+
+  fn writer(&self) -> &LocalWriter {
+    &self.writer
+  }
+}
+
+impl Drop for LocalChannel {
+  fn drop(&mut self) {
+    let _ = self.connection.close();
+  }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::io::connection::*;
+use crate::io::local_pipe::*;
+
+pub struct LocalConnection {
+  incoming: Arc<LocalPipe>,
+  outgoing: Arc<LocalPipe>,
+}
+
+impl LocalConnection {
+  pub(crate) fn new(
+    incoming: Arc<LocalPipe>,
+    outgoing: Arc<LocalPipe>,
+  ) -> Self {
+    LocalConnection { incoming, outgoing }
+  }
+}
+
+impl Connection for LocalConnection {
+  fn close(&self) -> std::io::Result<()> {
+    self.outgoing.close();
+    self.incoming.close();
+    Ok(())
+  }
+}
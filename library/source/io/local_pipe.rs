@@ -0,0 +1,65 @@
+use crate::io::buffer::*;
+use crate::routines::suspended_routine_queue::*;
+
+struct LocalPipeState {
+  data: Buffer,
+  is_closed: bool,
+  readers: SuspendedRoutineQueue,
+}
+
+pub(crate) struct LocalPipe {
+  state: std::sync::Mutex<LocalPipeState>,
+}
+
+unsafe impl Send for LocalPipe {}
+unsafe impl Sync for LocalPipe {}
+
+impl LocalPipe {
+  pub(crate) fn new() -> Self {
+    LocalPipe {
+      state: std::sync::Mutex::new(LocalPipeState {
+        data: Buffer::new(),
+        is_closed: false,
+        readers: SuspendedRoutineQueue::new(SuspendedRoutineNodeAdapter::new()),
+      }),
+    }
+  }
+
+  pub(crate) fn poll(&self) -> std::io::Result<bool> {
+    let state = self.state.lock().unwrap();
+    Ok(!state.data.is_empty() || state.is_closed)
+  }
+
+  pub(crate) fn read(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    let mut state = self.state.lock().unwrap();
+    while state.data.is_empty() && !state.is_closed {
+      let readers = &mut state.readers as *mut _;
+      suspend(unsafe { &mut *readers }, state);
+      state = self.state.lock().unwrap();
+    }
+    let count = size.min(state.data.len());
+    buffer.append(&state.data[..count]);
+    state.data.shrink_front(count);
+    Ok(count)
+  }
+
+  pub(crate) fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    let mut state = self.state.lock().unwrap();
+    if state.is_closed {
+      return Err(std::io::ErrorKind::BrokenPipe.into());
+    }
+    state.data.append(data);
+    resume(&mut state.readers);
+    Ok(())
+  }
+
+  pub(crate) fn close(&self) {
+    let mut state = self.state.lock().unwrap();
+    state.is_closed = true;
+    resume(&mut state.readers);
+  }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+use crate::io::local_pipe::*;
+use crate::io::reader::*;
+
+pub struct LocalReader {
+  pipe: Arc<LocalPipe>,
+}
+
+impl LocalReader {
+  pub(crate) fn new(pipe: Arc<LocalPipe>) -> Self {
+    LocalReader { pipe }
+  }
+}
+
+impl Reader for LocalReader {
+  fn poll(&self) -> std::io::Result<bool> {
+    self.pipe.poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    self.pipe.read(buffer, size)
+  }
+}
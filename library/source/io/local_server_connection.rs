@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use crate::io::connection::*;
+use crate::io::local_channel::*;
+use crate::io::server_connection::*;
+use crate::routines::suspended_routine_queue::*;
+
+struct LocalServerState {
+  pending_channels: VecDeque<LocalChannel>,
+  next_id: u64,
+  is_closed: bool,
+  acceptors: SuspendedRoutineQueue,
+}
+
+struct LocalServer {
+  name: String,
+  state: std::sync::Mutex<LocalServerState>,
+}
+
+unsafe impl Send for LocalServer {}
+unsafe impl Sync for LocalServer {}
+
+type LocalServers = std::sync::Mutex<HashMap<String, Arc<LocalServer>>>;
+
+fn get_local_servers() -> &'static LocalServers {
+  static SERVERS: OnceLock<LocalServers> = OnceLock::new();
+  SERVERS.get_or_init(Default::default)
+}
+
+pub struct LocalServerConnection {
+  server: Arc<LocalServer>,
+}
+
+impl LocalServerConnection {
+  pub fn new(name: &str) -> std::io::Result<Self> {
+    let mut servers = get_local_servers().lock().unwrap();
+    if servers.contains_key(name) {
+      return Err(std::io::ErrorKind::AddrInUse.into());
+    }
+    let server = Arc::new(LocalServer {
+      name: name.to_string(),
+      state: std::sync::Mutex::new(LocalServerState {
+        pending_channels: VecDeque::new(),
+        next_id: 0,
+        is_closed: false,
+        acceptors: SuspendedRoutineQueue::new(
+          SuspendedRoutineNodeAdapter::new(),
+        ),
+      }),
+    });
+    servers.insert(name.to_string(), server.clone());
+    Ok(LocalServerConnection { server })
+  }
+
+  pub fn name(&self) -> &str {
+    &self.server.name
+  }
+}
+
+impl Connection for LocalServerConnection {
+  fn close(&self) -> std::io::Result<()> {
+    let mut servers = get_local_servers().lock().unwrap();
+    if let Some(server) = servers.get(&self.server.name) {
+      if Arc::ptr_eq(server, &self.server) {
+        servers.remove(&self.server.name);
+      }
+    }
+    drop(servers);
+    let pending_channels = {
+      let mut state = self.server.state.lock().unwrap();
+      state.is_closed = true;
+      resume(&mut state.acceptors);
+      std::mem::take(&mut state.pending_channels)
+    };
+    drop(pending_channels);
+    Ok(())
+  }
+}
+
+impl ServerConnection for LocalServerConnection {
+  type Channel = LocalChannel;
+
+  fn accept(&self) -> std::io::Result<LocalChannel> {
+    let mut state = self.server.state.lock().unwrap();
+    loop {
+      if let Some(channel) = state.pending_channels.pop_front() {
+        return Ok(channel);
+      }
+      if state.is_closed {
+        return Err(std::io::ErrorKind::NotConnected.into());
+      }
+      let acceptors = &mut state.acceptors as *mut _;
+      suspend(unsafe { &mut *acceptors }, state);
+      state = self.server.state.lock().unwrap();
+    }
+  }
+}
+
+impl Drop for LocalServerConnection {
+  fn drop(&mut self) {
+    let _ = self.close();
+  }
+}
+
+pub(crate) fn connect_local(name: &str) -> std::io::Result<LocalChannel> {
+  let Some(server) = get_local_servers().lock().unwrap().get(name).cloned()
+  else {
+    return Err(std::io::ErrorKind::ConnectionRefused.into());
+  };
+  let mut state = server.state.lock().unwrap();
+  if state.is_closed {
+    return Err(std::io::ErrorKind::ConnectionRefused.into());
+  }
+  let id = state.next_id;
+  state.next_id += 1;
+  let (client, server_channel) =
+    LocalChannel::pair(server.name.clone(), format!("{}#{}", server.name, id));
+  state.pending_channels.push_back(server_channel);
+  resume(&mut state.acceptors);
+  Ok(client)
+}
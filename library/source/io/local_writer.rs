@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+use crate::io::local_pipe::*;
+use crate::io::writer::*;
+
+pub struct LocalWriter {
+  pipe: Arc<LocalPipe>,
+}
+
+impl LocalWriter {
+  pub(crate) fn new(pipe: Arc<LocalPipe>) -> Self {
+    LocalWriter { pipe }
+  }
+}
+
+impl Writer for LocalWriter {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    self.pipe.write(data)
+  }
+}
@@ -0,0 +1,104 @@
+mod buffer;
+mod buffer_reader;
+mod buffer_writer;
+mod buffered_reader;
+mod channel;
+mod connection;
+mod endianness;
+mod identifier;
+mod local_channel;
+mod local_connection;
+mod local_pipe;
+mod local_reader;
+mod local_server_connection;
+mod local_writer;
+mod multicast_interface;
+#[cfg(target_os = "linux")]
+mod multicast_socket;
+#[cfg(target_os = "linux")]
+mod peer_credentials;
+mod piped_reader;
+mod piped_writer;
+mod pipelined_writer;
+#[cfg(target_os = "linux")]
+mod reactor;
+mod reader;
+#[cfg(target_os = "linux")]
+mod scheduled_socket;
+mod server_connection;
+mod size_declarative_reader;
+mod size_declarative_writer;
+#[cfg(target_os = "linux")]
+mod socket_connection;
+#[cfg(target_os = "linux")]
+mod socket_reader;
+#[cfg(target_os = "linux")]
+mod socket_writer;
+#[cfg(target_os = "linux")]
+mod tcp_server_socket;
+#[cfg(target_os = "linux")]
+mod tcp_socket_channel;
+#[cfg(target_os = "linux")]
+mod tcp_socket_options;
+#[cfg(target_os = "linux")]
+mod udp_socket;
+#[cfg(target_os = "linux")]
+mod unix_server_socket;
+#[cfg(target_os = "linux")]
+mod unix_socket_address;
+#[cfg(target_os = "linux")]
+mod unix_socket_channel;
+#[cfg(target_os = "linux")]
+mod unix_socket_identifier;
+mod writer;
+
+pub use buffer::*;
+pub use buffer_reader::*;
+pub use buffer_writer::*;
+pub use buffered_reader::*;
+pub use channel::*;
+pub use connection::*;
+pub use endianness::*;
+pub use identifier::*;
+pub use local_channel::*;
+pub use local_connection::*;
+pub use local_reader::*;
+pub use local_server_connection::*;
+pub use local_writer::*;
+pub use multicast_interface::*;
+#[cfg(target_os = "linux")]
+pub use multicast_socket::*;
+#[cfg(target_os = "linux")]
+pub use peer_credentials::*;
+pub use piped_reader::*;
+pub use piped_writer::*;
+pub use pipelined_writer::*;
+#[cfg(target_os = "linux")]
+pub use reactor::*;
+pub use reader::*;
+pub use server_connection::*;
+pub use size_declarative_reader::*;
+pub use size_declarative_writer::*;
+#[cfg(target_os = "linux")]
+pub use socket_connection::*;
+#[cfg(target_os = "linux")]
+pub use socket_reader::*;
+#[cfg(target_os = "linux")]
+pub use socket_writer::*;
+#[cfg(target_os = "linux")]
+pub use tcp_server_socket::*;
+#[cfg(target_os = "linux")]
+pub use tcp_socket_channel::*;
+#[cfg(target_os = "linux")]
+pub use tcp_socket_options::*;
+#[cfg(target_os = "linux")]
+pub use udp_socket::*;
+#[cfg(target_os = "linux")]
+pub use unix_server_socket::*;
+#[cfg(target_os = "linux")]
+pub use unix_socket_address::*;
+#[cfg(target_os = "linux")]
+pub use unix_socket_channel::*;
+#[cfg(target_os = "linux")]
+pub use unix_socket_identifier::*;
+pub use writer::*;
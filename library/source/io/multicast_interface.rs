@@ -0,0 +1,9 @@
+use std::net::Ipv4Addr;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum MulticastInterface {
+  #[default]
+  Any,
+  Address(Ipv4Addr),
+  Index(u32),
+}
@@ -0,0 +1,157 @@
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+
+use crate::io::buffer::*;
+use crate::io::connection::*;
+use crate::io::multicast_interface::*;
+use crate::io::udp_socket::*;
+
+pub struct MulticastSocket {
+  socket: UdpSocket,
+  is_ipv6: bool,
+}
+
+impl MulticastSocket {
+  pub fn bind(port: u16, is_ipv6: bool) -> std::io::Result<Self> {
+    let address = if is_ipv6 {
+      SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), port)
+    } else {
+      SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), port)
+    };
+    Ok(MulticastSocket {
+      socket: UdpSocket::bind_address(address, true)?,
+      is_ipv6,
+    })
+  }
+
+  pub fn udp_socket(&self) -> &UdpSocket {
+    &self.socket
+  }
+
+  pub fn local_address(&self) -> std::io::Result<SocketAddr> {
+    self.socket.local_address()
+  }
+
+  pub fn join(
+    &self,
+    group: IpAddr,
+    interface: MulticastInterface,
+  ) -> std::io::Result<()> {
+    let socket = self.socket.socket();
+    match group {
+      IpAddr::V4(group) => {
+        socket.join_multicast_v4_n(&group, &to_request(interface))
+      }
+      IpAddr::V6(group) => {
+        socket.join_multicast_v6(&group, to_index(interface)?)
+      }
+    }
+  }
+
+  pub fn leave(
+    &self,
+    group: IpAddr,
+    interface: MulticastInterface,
+  ) -> std::io::Result<()> {
+    let socket = self.socket.socket();
+    match group {
+      IpAddr::V4(group) => {
+        socket.leave_multicast_v4_n(&group, &to_request(interface))
+      }
+      IpAddr::V6(group) => {
+        socket.leave_multicast_v6(&group, to_index(interface)?)
+      }
+    }
+  }
+
+  pub fn set_outgoing_interface(
+    &self,
+    interface: MulticastInterface,
+  ) -> std::io::Result<()> {
+    let socket = self.socket.socket();
+    if self.is_ipv6 {
+      socket.set_multicast_if_v6(to_index(interface)?)
+    } else {
+      match interface {
+        MulticastInterface::Any => {
+          socket.set_multicast_if_v4(&Ipv4Addr::UNSPECIFIED)
+        }
+        MulticastInterface::Address(address) => {
+          socket.set_multicast_if_v4(&address)
+        }
+        MulticastInterface::Index(_) => Err(std::io::Error::new(
+          std::io::ErrorKind::InvalidInput,
+          "IPv4 outgoing interface must be an address.",
+        )),
+      }
+    }
+  }
+
+  pub fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+    let socket = self.socket.socket();
+    if self.is_ipv6 {
+      socket.set_multicast_hops_v6(ttl)
+    } else {
+      socket.set_multicast_ttl_v4(ttl)
+    }
+  }
+
+  pub fn set_loopback(&self, is_enabled: bool) -> std::io::Result<()> {
+    let socket = self.socket.socket();
+    if self.is_ipv6 {
+      socket.set_multicast_loop_v6(is_enabled)
+    } else {
+      socket.set_multicast_loop_v4(is_enabled)
+    }
+  }
+
+  pub fn send_to(
+    &self,
+    data: &[u8],
+    address: SocketAddr,
+  ) -> std::io::Result<usize> {
+    self.socket.send_to(data, address)
+  }
+
+  pub fn receive_from(
+    &self,
+    buffer: &mut Buffer,
+  ) -> std::io::Result<(usize, SocketAddr)> {
+    self.socket.receive_from(buffer)
+  }
+}
+
+impl Connection for MulticastSocket {
+  fn close(&self) -> std::io::Result<()> {
+    self.socket.close()
+  }
+}
+
+fn to_request(
+  interface: MulticastInterface,
+) -> socket2::InterfaceIndexOrAddress {
+  match interface {
+    MulticastInterface::Any => {
+      socket2::InterfaceIndexOrAddress::Address(Ipv4Addr::UNSPECIFIED)
+    }
+    MulticastInterface::Address(address) => {
+      socket2::InterfaceIndexOrAddress::Address(address)
+    }
+    MulticastInterface::Index(index) => {
+      socket2::InterfaceIndexOrAddress::Index(index)
+    }
+  }
+}
+
+fn to_index(interface: MulticastInterface) -> std::io::Result<u32> {
+  match interface {
+    MulticastInterface::Any => Ok(0),
+    MulticastInterface::Index(index) => Ok(index),
+    MulticastInterface::Address(_) => Err(std::io::Error::new(
+      std::io::ErrorKind::InvalidInput,
+      "IPv6 interface must be an index.",
+    )),
+  }
+}
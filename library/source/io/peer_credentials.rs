@@ -0,0 +1,36 @@
+use std::os::fd::RawFd;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PeerCredentials {
+  pub pid: i32,
+  pub uid: u32,
+  pub gid: u32,
+}
+
+impl PeerCredentials {
+  pub(crate) fn from_fd(fd: RawFd) -> std::io::Result<Self> {
+    let mut credentials = libc::ucred {
+      pid: 0,
+      uid: 0,
+      gid: 0,
+    };
+    let mut length = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
+    let result = unsafe {
+      libc::getsockopt(
+        fd,
+        libc::SOL_SOCKET,
+        libc::SO_PEERCRED,
+        &mut credentials as *mut libc::ucred as *mut libc::c_void,
+        &mut length,
+      )
+    };
+    if result < 0 {
+      return Err(std::io::Error::last_os_error());
+    }
+    Ok(PeerCredentials {
+      pid: credentials.pid,
+      uid: credentials.uid,
+      gid: credentials.gid,
+    })
+  }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+use crate::io::connection::*;
+use crate::io::local_pipe::*;
+use crate::io::reader::*;
+
+pub struct PipedReader {
+  pipe: Arc<LocalPipe>,
+}
+
+impl PipedReader {
+  pub fn new() -> Self {
+    PipedReader {
+      pipe: Arc::new(LocalPipe::new()),
+    }
+  }
+
+  pub(crate) fn pipe(&self) -> &Arc<LocalPipe> {
+    &self.pipe
+  }
+}
+
+impl Default for PipedReader {
+  fn default() -> Self {
+    PipedReader::new()
+  }
+}
+
+impl Connection for PipedReader {
+  fn close(&self) -> std::io::Result<()> {
+    self.pipe.close();
+    Ok(())
+  }
+}
+
+impl Reader for PipedReader {
+  fn poll(&self) -> std::io::Result<bool> {
+    self.pipe.poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    self.pipe.read(buffer, size)
+  }
+}
+
+impl Drop for PipedReader {
+  fn drop(&mut self) {
+    self.pipe.close();
+  }
+}
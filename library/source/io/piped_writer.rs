@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+use crate::io::connection::*;
+use crate::io::local_pipe::*;
+use crate::io::piped_reader::*;
+use crate::io::writer::*;
+
+pub struct PipedWriter {
+  pipe: Arc<LocalPipe>,
+}
+
+impl PipedWriter {
+  pub fn new(reader: &PipedReader) -> Self {
+    PipedWriter {
+      pipe: reader.pipe().clone(),
+    }
+  }
+}
+
+impl Connection for PipedWriter {
+  fn close(&self) -> std::io::Result<()> {
+    self.pipe.close();
+    Ok(())
+  }
+}
+
+impl Writer for PipedWriter {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    self.pipe.write(data)
+  }
+}
+
+impl Drop for PipedWriter {
+  fn drop(&mut self) {
+    self.pipe.close();
+  }
+}
@@ -0,0 +1,97 @@
+use crate::io::buffer::*;
+use crate::io::writer::*;
+use crate::routines::suspended_routine_queue::*;
+
+struct PipelineState {
+  pending: Buffer,
+  pending_batch: u64,
+  flushed_batch: u64,
+  is_flushing: bool,
+  error: Option<(u64, std::io::ErrorKind)>,
+  writers: SuspendedRoutineQueue,
+}
+
+impl PipelineState {
+  fn result(&self, batch: u64) -> std::io::Result<()> {
+    match self.error {
+      Some((failed_batch, error)) if batch >= failed_batch => Err(error.into()),
+      _ => Ok(()),
+    }
+  }
+}
+
+pub struct PipelinedWriter<W: Writer> {
+  destination: W,
+  state: std::sync::Mutex<PipelineState>,
+}
+
+unsafe impl<W: Writer> Send for PipelinedWriter<W> {}
+unsafe impl<W: Writer> Sync for PipelinedWriter<W> {}
+
+impl<W: Writer> PipelinedWriter<W> {
+  pub fn new(destination: W) -> Self {
+    PipelinedWriter {
+      destination,
+      state: std::sync::Mutex::new(PipelineState {
+        pending: Buffer::new(),
+        pending_batch: 1,
+        flushed_batch: 0,
+        is_flushing: false,
+        error: None,
+        writers: SuspendedRoutineQueue::new(SuspendedRoutineNodeAdapter::new()),
+      }),
+    }
+  }
+
+  pub fn destination(&self) -> &W {
+    &self.destination
+  }
+
+  fn flush(&self) {
+    loop {
+      let (batch, pending) = {
+        let mut state = self.state.lock().unwrap();
+        if state.pending.is_empty() {
+          state.is_flushing = false;
+          return;
+        }
+        let batch = state.pending_batch;
+        state.pending_batch += 1;
+        (batch, std::mem::take(&mut state.pending))
+      };
+      let result = self.destination.write(&pending);
+      let mut state = self.state.lock().unwrap();
+      if let Err(error) = result {
+        state.error = Some((batch, error.kind()));
+        state.pending.clear();
+        state.flushed_batch = state.pending_batch;
+      } else {
+        state.flushed_batch = batch;
+      }
+      resume(&mut state.writers);
+    }
+  }
+}
+
+impl<W: Writer> Writer for PipelinedWriter<W> {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    let mut state = self.state.lock().unwrap();
+    if let Some((_, error)) = state.error {
+      return Err(error.into());
+    }
+    state.pending.append(data);
+    let batch = state.pending_batch;
+    if !state.is_flushing {
+      state.is_flushing = true;
+      drop(state);
+      self.flush();
+      return self.state.lock().unwrap().result(batch);
+    }
+    while state.flushed_batch < batch {
+      let writers = &mut state.writers as *mut _;
+      suspend(unsafe { &mut *writers }, state);
+      state = self.state.lock().unwrap();
+    }
+    state.result(batch)
+  }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::os::fd::RawFd;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use crate::routines::*;
+
+const MAX_EVENTS: usize = 64;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Interest {
+  Readable,
+  Writable,
+}
+
+#[derive(Default)]
+struct Registration {
+  is_registered: bool,
+  readers: Vec<Promise<(), std::io::Error>>,
+  writers: Vec<Promise<(), std::io::Error>>,
+}
+
+impl Registration {
+  fn events(&self) -> u32 {
+    let mut events = libc::EPOLLONESHOT as u32;
+    if !self.readers.is_empty() {
+      events |= (libc::EPOLLIN | libc::EPOLLRDHUP) as u32;
+    }
+    if !self.writers.is_empty() {
+      events |= libc::EPOLLOUT as u32;
+    }
+    events
+  }
+}
+
+pub struct Reactor {
+  epoll_fd: RawFd,
+  registrations: Mutex<HashMap<RawFd, Registration>>,
+}
+
+impl Reactor {
+  fn new() -> std::io::Result<Self> {
+    let epoll_fd = unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) };
+    if epoll_fd < 0 {
+      return Err(std::io::Error::last_os_error());
+    }
+    Ok(Reactor {
+      epoll_fd,
+      registrations: Mutex::new(HashMap::new()),
+    })
+  }
+
+  pub fn wait(&self, fd: RawFd, interest: Interest) -> std::io::Result<()> {
+    let (promise, future) = Promise::new_link();
+    {
+      let mut registrations = self.registrations.lock().unwrap();
+      let registration = registrations.entry(fd).or_default();
+      match interest {
+        Interest::Readable => registration.readers.push(promise),
+        Interest::Writable => registration.writers.push(promise),
+      }
+      if let Err(error) = self.arm(fd, registration) {
+        match interest {
+          Interest::Readable => registration.readers.pop(),
+          Interest::Writable => registration.writers.pop(),
+        };
+        if !registration.is_registered {
+          registrations.remove(&fd);
+        }
+        return Err(error);
+      }
+    }
+    future
+      .cancellable_result()
+      .unwrap_or_else(|| Err(std::io::Error::other("Routine was cancelled.")))
+  }
+
+  pub fn wait_readable(&self, fd: RawFd) -> std::io::Result<()> {
+    self.wait(fd, Interest::Readable)
+  }
+
+  pub fn wait_writable(&self, fd: RawFd) -> std::io::Result<()> {
+    self.wait(fd, Interest::Writable)
+  }
+
+  pub fn deregister(&self, fd: RawFd) {
+    let registration = self.registrations.lock().unwrap().remove(&fd);
+    if let Some(registration) = registration {
+      if registration.is_registered {
+        unsafe {
+          libc::epoll_ctl(
+            self.epoll_fd,
+            libc::EPOLL_CTL_DEL,
+            fd,
+            std::ptr::null_mut(),
+          )
+        };
+      }
+      fail(registration, std::io::ErrorKind::NotConnected);
+    }
+  }
+
+  fn arm(
+    &self,
+    fd: RawFd,
+    registration: &mut Registration,
+  ) -> std::io::Result<()> {
+    let mut event = libc::epoll_event {
+      events: registration.events(),
+      u64: fd as u64,
+    };
+    let operation = if registration.is_registered {
+      libc::EPOLL_CTL_MOD
+    } else {
+      libc::EPOLL_CTL_ADD
+    };
+    if unsafe { libc::epoll_ctl(self.epoll_fd, operation, fd, &mut event) } < 0
+    {
+      return Err(std::io::Error::last_os_error());
+    }
+    registration.is_registered = true;
+    Ok(())
+  }
+
+  fn run(&self) {
+    let mut events = [libc::epoll_event { events: 0, u64: 0 }; MAX_EVENTS];
+    loop {
+      let count = unsafe {
+        libc::epoll_wait(
+          self.epoll_fd,
+          events.as_mut_ptr(),
+          MAX_EVENTS as i32,
+          -1,
+        )
+      };
+      if count < 0 {
+        if std::io::Error::last_os_error().kind()
+          == std::io::ErrorKind::Interrupted
+        {
+          continue;
+        }
+        return;
+      }
+      for event in &events[..count as usize] {
+        self.dispatch(event.u64 as RawFd, event.events);
+      }
+    }
+  }
+
+  fn dispatch(&self, fd: RawFd, events: u32) {
+    let is_closed = events & (libc::EPOLLERR | libc::EPOLLHUP) as u32 != 0;
+    let is_readable =
+      is_closed || events & (libc::EPOLLIN | libc::EPOLLRDHUP) as u32 != 0;
+    let is_writable = is_closed || events & libc::EPOLLOUT as u32 != 0;
+    let mut ready = Vec::new();
+    {
+      let mut registrations = self.registrations.lock().unwrap();
+      let Some(registration) = registrations.get_mut(&fd) else {
+        return;
+      };
+      if is_readable {
+        ready.append(&mut registration.readers);
+      }
+      if is_writable {
+        ready.append(&mut registration.writers);
+      }
+      if !registration.readers.is_empty() || !registration.writers.is_empty() {
+        if let Err(error) = self.arm(fd, registration) {
+          let registration = registrations.remove(&fd).unwrap();
+          fail(registration, error.kind());
+        }
+      }
+    }
+    for promise in ready {
+      promise.resolve(());
+    }
+  }
+}
+
+fn fail(registration: Registration, kind: std::io::ErrorKind) {
+  for promise in registration.readers.into_iter() {
+    promise.reject(std::io::Error::from(kind));
+  }
+  for promise in registration.writers.into_iter() {
+    promise.reject(std::io::Error::from(kind));
+  }
+}
+
+static REACTOR: OnceLock<Reactor> = OnceLock::new();
+
+pub fn get_reactor() -> &'static Reactor {
+  REACTOR.get_or_init(|| {
+    let reactor = Reactor::new().unwrap();
+    std::thread::Builder::new()
+      .name(String::from("beam-reactor"))
+      .spawn(|| get_reactor().run())
+      .unwrap();
+    reactor
+  })
+}
+
+pub fn wait_readable(fd: RawFd) -> std::io::Result<()> {
+  get_reactor().wait_readable(fd)
+}
+
+pub fn wait_writable(fd: RawFd) -> std::io::Result<()> {
+  get_reactor().wait_writable(fd)
+}
+
+pub fn deregister(fd: RawFd) {
+  get_reactor().deregister(fd);
+}
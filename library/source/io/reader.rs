@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+
+pub const DEFAULT_READ_SIZE: usize = 8 * 1024;
+
+pub trait Reader: Send + Sync {
+  fn poll(&self) -> std::io::Result<bool>;
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize>;
+
+  fn read(&self, buffer: &mut Buffer) -> std::io::Result<usize> {
+    self.read_size(buffer, DEFAULT_READ_SIZE)
+  }
+
+  fn read_exact(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<()> {
+    let mut remaining = size;
+    while remaining != 0 {
+      let count = self.read_size(buffer, remaining)?;
+      if count == 0 {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+      }
+      remaining -= count;
+    }
+    Ok(())
+  }
+}
+
+impl<T: Reader + ?Sized> Reader for &T {
+  fn poll(&self) -> std::io::Result<bool> {
+    (**self).poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    (**self).read_size(buffer, size)
+  }
+
+  fn read(&self, buffer: &mut Buffer) -> std::io::Result<usize> {
+    (**self).read(buffer)
+  }
+
+  fn read_exact(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<()> {
+    (**self).read_exact(buffer, size)
+  }
+}
+
+impl<T: Reader + ?Sized> Reader for Box<T> {
+  fn poll(&self) -> std::io::Result<bool> {
+    (**self).poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    (**self).read_size(buffer, size)
+  }
+
+  fn read(&self, buffer: &mut Buffer) -> std::io::Result<usize> {
+    (**self).read(buffer)
+  }
+
+  fn read_exact(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<()> {
+    (**self).read_exact(buffer, size)
+  }
+}
+
+impl<T: Reader + ?Sized> Reader for Arc<T> {
+  fn poll(&self) -> std::io::Result<bool> {
+    (**self).poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    (**self).read_size(buffer, size)
+  }
+
+  fn read(&self, buffer: &mut Buffer) -> std::io::Result<usize> {
+    (**self).read(buffer)
+  }
+
+  fn read_exact(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<()> {
+    (**self).read_exact(buffer, size)
+  }
+}
@@ -0,0 +1,162 @@
+use std::net::SocketAddr;
+use std::net::ToSocketAddrs;
+use std::os::fd::AsRawFd;
+use std::os::fd::RawFd;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use socket2::SockAddr;
+use socket2::Socket;
+
+use crate::io::buffer::*;
+use crate::io::reactor::*;
+use crate::routines::*;
+
+pub(crate) struct ScheduledSocket {
+  socket: Socket,
+  is_closed: AtomicBool,
+}
+
+impl ScheduledSocket {
+  pub(crate) fn new(socket: Socket) -> std::io::Result<Self> {
+    socket.set_nonblocking(true)?;
+    Ok(ScheduledSocket {
+      socket,
+      is_closed: AtomicBool::new(false),
+    })
+  }
+
+  pub(crate) fn socket(&self) -> &Socket {
+    &self.socket
+  }
+
+  pub(crate) fn fd(&self) -> RawFd {
+    self.socket.as_raw_fd()
+  }
+
+  pub(crate) fn poll(&self) -> std::io::Result<bool> {
+    self.check_open()?;
+    let mut descriptor = libc::pollfd {
+      fd: self.fd(),
+      events: libc::POLLIN,
+      revents: 0,
+    };
+    let result = unsafe { libc::poll(&mut descriptor, 1, 0) };
+    if result < 0 {
+      return Err(std::io::Error::last_os_error());
+    }
+    Ok(result != 0)
+  }
+
+  pub(crate) fn connect(&self, address: &SockAddr) -> std::io::Result<()> {
+    match self.socket.connect(address) {
+      Ok(()) => return Ok(()),
+      Err(error) if error.raw_os_error() == Some(libc::EINPROGRESS) => {}
+      Err(error) => return Err(error),
+    }
+    wait_writable(self.fd())?;
+    match self.socket.take_error()? {
+      Some(error) => Err(error),
+      None => Ok(()),
+    }
+  }
+
+  pub(crate) fn accept(&self) -> std::io::Result<(Socket, SockAddr)> {
+    self.retry(Interest::Readable, || self.socket.accept())
+  }
+
+  pub(crate) fn read(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    let count = self.retry(Interest::Readable, || {
+      self.socket.recv(buffer.spare_capacity(size))
+    })?;
+    unsafe { buffer.commit(count) };
+    Ok(count)
+  }
+
+  pub(crate) fn write(&self, data: &[u8]) -> std::io::Result<()> {
+    let mut position = 0;
+    while position != data.len() {
+      position += self
+        .retry(Interest::Writable, || self.socket.send(&data[position..]))?;
+    }
+    Ok(())
+  }
+
+  pub(crate) fn send_to(
+    &self,
+    data: &[u8],
+    address: &SockAddr,
+  ) -> std::io::Result<usize> {
+    self.retry(Interest::Writable, || self.socket.send_to(data, address))
+  }
+
+  pub(crate) fn receive_from(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<(usize, SockAddr)> {
+    let (count, address) = self.retry(Interest::Readable, || {
+      self.socket.recv_from(buffer.spare_capacity(size))
+    })?;
+    unsafe { buffer.commit(count) };
+    Ok((count, address))
+  }
+
+  pub(crate) fn close(&self) -> std::io::Result<()> {
+    if self.is_closed.swap(true, Ordering::AcqRel) {
+      return Ok(());
+    }
+    let result = match self.socket.shutdown(std::net::Shutdown::Both) {
+      Err(error) if error.kind() == std::io::ErrorKind::NotConnected => Ok(()),
+      result => result,
+    };
+    deregister(self.fd());
+    result
+  }
+
+  fn check_open(&self) -> std::io::Result<()> {
+    if self.is_closed.load(Ordering::Acquire) {
+      return Err(std::io::ErrorKind::NotConnected.into());
+    }
+    Ok(())
+  }
+
+  fn retry<T>(
+    &self,
+    interest: Interest,
+    mut operation: impl FnMut() -> std::io::Result<T>,
+  ) -> std::io::Result<T> {
+    loop {
+      self.check_open()?;
+      match operation() {
+        Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {
+          get_reactor().wait(self.fd(), interest)?
+        }
+        Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+        result => return result,
+      }
+    }
+  }
+}
+
+impl Drop for ScheduledSocket {
+  fn drop(&mut self) {
+    deregister(self.fd());
+  }
+}
+
+pub(crate) fn resolve(
+  address: impl ToSocketAddrs + Send + 'static,
+) -> std::io::Result<Vec<SocketAddr>> {
+  spawn_blocking(move || {
+    address
+      .to_socket_addrs()
+      .map(|addresses| addresses.collect::<Vec<_>>())
+  })
+  .result()
+  .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+}
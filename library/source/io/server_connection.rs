@@ -0,0 +1,8 @@
+use crate::io::channel::*;
+use crate::io::connection::*;
+
+pub trait ServerConnection: Connection {
+  type Channel: Channel;
+
+  fn accept(&self) -> std::io::Result<Self::Channel>;
+}
@@ -0,0 +1,113 @@
+use crate::io::buffer::*;
+use crate::io::reader::*;
+use crate::threading::Mutex;
+
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+pub struct SizeDeclarativeReader<R: Reader> {
+  source: R,
+  max_message_size: usize,
+  remaining: Mutex<Option<usize>>,
+}
+
+impl<R: Reader> SizeDeclarativeReader<R> {
+  pub fn new(source: R) -> Self {
+    SizeDeclarativeReader::with_max_message_size(
+      source,
+      DEFAULT_MAX_MESSAGE_SIZE,
+    )
+  }
+
+  pub fn with_max_message_size(source: R, max_message_size: usize) -> Self {
+    SizeDeclarativeReader {
+      source,
+      max_message_size,
+      remaining: Mutex::new(None),
+    }
+  }
+
+  pub fn source(&self) -> &R {
+    &self.source
+  }
+
+  pub fn max_message_size(&self) -> usize {
+    self.max_message_size
+  }
+
+  pub fn read_message(
+    &self,
+    buffer: &mut Buffer,
+  ) -> std::io::Result<Option<usize>> {
+    let mut remaining = self.remaining.lock();
+    let size = match remaining.take() {
+      Some(size) => size,
+      None => match self.read_header()? {
+        Some(size) => size,
+        None => return Ok(None),
+      },
+    };
+    self.source.read_exact(buffer, size)?;
+    Ok(Some(size))
+  }
+
+  fn read_header(&self) -> std::io::Result<Option<usize>> {
+    let mut header = Buffer::with_capacity(4);
+    while header.len() < 4 {
+      let size = 4 - header.len();
+      if self.source.read_size(&mut header, size)? == 0 {
+        if header.is_empty() {
+          return Ok(None);
+        }
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+      }
+    }
+    let size = header.get::<u32>(0).unwrap() as usize;
+    if size > self.max_message_size {
+      return Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        "Message exceeds the maximum size.",
+      ));
+    }
+    Ok(Some(size))
+  }
+}
+
+impl<R: Reader> Reader for SizeDeclarativeReader<R> {
+  fn poll(&self) -> std::io::Result<bool> {
+    self.source.poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    let mut remaining = self.remaining.lock();
+    let frame_size = loop {
+      match *remaining {
+        Some(frame_size) => break frame_size,
+        None => match self.read_header()? {
+          Some(0) => continue,
+          Some(frame_size) => *remaining = Some(frame_size),
+          None => return Ok(0),
+        },
+      }
+    };
+    let count = self.source.read_size(buffer, size.min(frame_size))?;
+    if count == 0 {
+      return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    *remaining = Some(frame_size - count).filter(|&size| size != 0);
+    Ok(count)
+  }
+
+  fn read(&self, buffer: &mut Buffer) -> std::io::Result<usize> {
+    loop {
+      match self.read_message(buffer)? {
+        Some(0) => continue,
+        Some(size) => return Ok(size),
+        None => return Ok(0),
+      }
+    }
+  }
+}
@@ -0,0 +1,31 @@
+use crate::io::buffer::*;
+use crate::io::writer::*;
+
+pub struct SizeDeclarativeWriter<W: Writer> {
+  destination: W,
+}
+
+impl<W: Writer> SizeDeclarativeWriter<W> {
+  pub fn new(destination: W) -> Self {
+    SizeDeclarativeWriter { destination }
+  }
+
+  pub fn destination(&self) -> &W {
+    &self.destination
+  }
+}
+
+impl<W: Writer> Writer for SizeDeclarativeWriter<W> {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    let size = u32::try_from(data.len()).map_err(|_| {
+      std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "Message exceeds the maximum size.",
+      )
+    })?;
+    let mut message = Buffer::with_capacity(4 + data.len());
+    message.push(size);
+    message.append(data);
+    self.destination.write(&message)
+  }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::io::connection::*;
+use crate::io::scheduled_socket::*;
+
+pub struct SocketConnection {
+  socket: Arc<ScheduledSocket>,
+}
+
+impl SocketConnection {
+  pub(crate) fn new(socket: Arc<ScheduledSocket>) -> Self {
+    SocketConnection { socket }
+  }
+}
+
+impl Connection for SocketConnection {
+  fn close(&self) -> std::io::Result<()> {
+    self.socket.close()
+  }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+use crate::io::reader::*;
+use crate::io::scheduled_socket::*;
+
+pub struct SocketReader {
+  socket: Arc<ScheduledSocket>,
+}
+
+impl SocketReader {
+  pub(crate) fn new(socket: Arc<ScheduledSocket>) -> Self {
+    SocketReader { socket }
+  }
+
+  pub(crate) fn socket(&self) -> &ScheduledSocket {
+    &self.socket
+  }
+}
+
+impl Reader for SocketReader {
+  fn poll(&self) -> std::io::Result<bool> {
+    self.socket.poll()
+  }
+
+  fn read_size(
+    &self,
+    buffer: &mut Buffer,
+    size: usize,
+  ) -> std::io::Result<usize> {
+    self.socket.read(buffer, size)
+  }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+use crate::io::scheduled_socket::*;
+use crate::io::writer::*;
+
+pub struct SocketWriter {
+  socket: Arc<ScheduledSocket>,
+}
+
+impl SocketWriter {
+  pub(crate) fn new(socket: Arc<ScheduledSocket>) -> Self {
+    SocketWriter { socket }
+  }
+}
+
+impl Writer for SocketWriter {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    self.socket.write(data)
+  }
+}
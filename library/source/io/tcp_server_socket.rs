@@ -0,0 +1,97 @@
+use std::net::SocketAddr;
+use std::net::ToSocketAddrs;
+
+use socket2::Domain;
+use socket2::Protocol;
+use socket2::Socket;
+use socket2::Type;
+
+use crate::io::connection::*;
+use crate::io::scheduled_socket::*;
+use crate::io::server_connection::*;
+use crate::io::tcp_socket_channel::*;
+use crate::io::tcp_socket_options::*;
+
+const DEFAULT_BACKLOG: i32 = 1024;
+
+pub struct TcpServerSocket {
+  socket: ScheduledSocket,
+  options: TcpSocketOptions,
+}
+
+impl TcpServerSocket {
+  pub fn bind(
+    address: impl ToSocketAddrs + Send + 'static,
+  ) -> std::io::Result<Self> {
+    TcpServerSocket::bind_with_options(address, TcpSocketOptions::default())
+  }
+
+  pub fn bind_with_options(
+    address: impl ToSocketAddrs + Send + 'static,
+    options: TcpSocketOptions,
+  ) -> std::io::Result<Self> {
+    let mut last_error = None;
+    for address in resolve(address)? {
+      match bind_address(address, &options) {
+        Ok(socket) => {
+          return Ok(TcpServerSocket {
+            socket: ScheduledSocket::new(socket)?,
+            options,
+          })
+        }
+        Err(error) => last_error = Some(error),
+      }
+    }
+    Err(last_error.unwrap_or_else(|| {
+      std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "No addresses to bind to.",
+      )
+    }))
+  }
+
+  pub fn local_address(&self) -> std::io::Result<SocketAddr> {
+    local_address(self.socket.socket())
+  }
+}
+
+impl Connection for TcpServerSocket {
+  fn close(&self) -> std::io::Result<()> {
+    self.socket.close()
+  }
+}
+
+impl ServerConnection for TcpServerSocket {
+  type Channel = TcpSocketChannel;
+
+  fn accept(&self) -> std::io::Result<TcpSocketChannel> {
+    let (socket, address) = self.socket.accept()?;
+    let identifier = address.as_socket().ok_or_else(|| {
+      std::io::Error::new(std::io::ErrorKind::InvalidData, "Not an IP address.")
+    })?;
+    self.options.apply_to_connection(&socket)?;
+    TcpSocketChannel::from_socket(socket, identifier)
+  }
+}
+
+impl Drop for TcpServerSocket {
+  fn drop(&mut self) {
+    let _ = self.close();
+  }
+}
+
+fn bind_address(
+  address: SocketAddr,
+  options: &TcpSocketOptions,
+) -> std::io::Result<Socket> {
+  let socket = Socket::new(
+    Domain::for_address(address),
+    Type::STREAM,
+    Some(Protocol::TCP),
+  )?;
+  socket.set_reuse_address(true)?;
+  options.apply_to_listener(&socket)?;
+  socket.bind(&address.into())?;
+  socket.listen(DEFAULT_BACKLOG)?;
+  Ok(socket)
+}
@@ -0,0 +1,138 @@
+use std::net::SocketAddr;
+use std::net::ToSocketAddrs;
+use std::os::fd::AsFd;
+use std::os::fd::BorrowedFd;
+use std::sync::Arc;
+
+use socket2::Domain;
+use socket2::Protocol;
+use socket2::Socket;
+use socket2::Type;
+
+use crate::io::channel::*;
+use crate::io::connection::*;
+use crate::io::scheduled_socket::*;
+use crate::io::socket_connection::*;
+use crate::io::socket_reader::*;
+use crate::io::socket_writer::*;
+use crate::io::tcp_socket_options::*;
+
+pub struct TcpSocketChannel {
+  identifier: SocketAddr,
+  connection: SocketConnection,
+  reader: SocketReader,
+  writer: SocketWriter,
+}
+
+impl TcpSocketChannel {
+  pub fn connect(
+    address: impl ToSocketAddrs + Send + 'static,
+  ) -> std::io::Result<Self> {
+    TcpSocketChannel::connect_with_options(
+      address,
+      &TcpSocketOptions::default(),
+    )
+  }
+
+  pub fn connect_with_options(
+    address: impl ToSocketAddrs + Send + 'static,
+    options: &TcpSocketOptions,
+  ) -> std::io::Result<Self> {
+    TcpSocketChannel::connect_addresses(&resolve(address)?, options)
+  }
+
+  pub fn connect_addresses(
+    addresses: &[SocketAddr],
+    options: &TcpSocketOptions,
+  ) -> std::io::Result<Self> {
+    let mut last_error = None;
+    for address in addresses {
+      match connect_address(*address, options) {
+        Ok(channel) => return Ok(channel),
+        Err(error) => last_error = Some(error),
+      }
+    }
+    Err(last_error.unwrap_or_else(|| {
+      std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "No addresses to connect to.",
+      )
+    }))
+  }
+
+  pub(crate) fn from_socket(
+    socket: Socket,
+    identifier: SocketAddr,
+  ) -> std::io::Result<Self> {
+    let socket = Arc::new(ScheduledSocket::new(socket)?);
+    Ok(TcpSocketChannel {
+      identifier,
+      connection: SocketConnection::new(socket.clone()),
+      reader: SocketReader::new(socket.clone()),
+      writer: SocketWriter::new(socket),
+    })
+  }
+
+  pub fn local_address(&self) -> std::io::Result<SocketAddr> {
+    local_address(self.reader.socket().socket())
+  }
+}
+
+impl Channel for TcpSocketChannel {
+  type Identifier = SocketAddr;
+  type Connection = SocketConnection;
+  type Reader = SocketReader;
+  type Writer = SocketWriter;
+
+  fn identifier(&self) -> &SocketAddr {
+    &self.identifier
+  }
+
+  fn connection(&self) -> &SocketConnection {
+    &self.connection
+  }
+
+  fn reader(&self) -> &SocketReader {
+    &self.reader
+  }
+
+  fn writer(&self) -> &SocketWriter {
+    &self.writer
+  }
+}
+
+impl AsFd for TcpSocketChannel {
+  fn as_fd(&self) -> BorrowedFd<'_> {
+    self.reader.socket().socket().as_fd()
+  }
+}
+
+impl Drop for TcpSocketChannel {
+  fn drop(&mut self) {
+    let _ = self.connection.close();
+  }
+}
+
+fn connect_address(
+  address: SocketAddr,
+  options: &TcpSocketOptions,
+) -> std::io::Result<TcpSocketChannel> {
+  let socket = Socket::new(
+    Domain::for_address(address),
+    Type::STREAM,
+    Some(Protocol::TCP),
+  )?;
+  options.apply(&socket)?;
+  if let Some(interface) = options.interface {
+    socket.bind(&SocketAddr::new(interface, 0).into())?;
+  }
+  let channel = TcpSocketChannel::from_socket(socket, address)?;
+  channel.reader.socket().connect(&address.into())?;
+  Ok(channel)
+}
+
+pub(crate) fn local_address(socket: &Socket) -> std::io::Result<SocketAddr> {
+  socket.local_addr()?.as_socket().ok_or_else(|| {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, "Not an IP address.")
+  })
+}
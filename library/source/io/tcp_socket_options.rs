@@ -0,0 +1,51 @@
+use std::net::IpAddr;
+use std::time::Duration;
+
+use socket2::Socket;
+use socket2::TcpKeepalive;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TcpSocketOptions {
+  pub no_delay: bool,
+  pub keep_alive: Option<Duration>,
+  pub send_buffer_size: Option<usize>,
+  pub receive_buffer_size: Option<usize>,
+  pub interface: Option<IpAddr>,
+  pub device: Option<String>,
+}
+
+impl TcpSocketOptions {
+  pub(crate) fn apply(&self, socket: &Socket) -> std::io::Result<()> {
+    self.apply_to_listener(socket)?;
+    self.apply_to_connection(socket)
+  }
+
+  pub(crate) fn apply_to_listener(
+    &self,
+    socket: &Socket,
+  ) -> std::io::Result<()> {
+    if let Some(size) = self.send_buffer_size {
+      socket.set_send_buffer_size(size)?;
+    }
+    if let Some(size) = self.receive_buffer_size {
+      socket.set_recv_buffer_size(size)?;
+    }
+    if let Some(device) = &self.device {
+      socket.bind_device(Some(device.as_bytes()))?;
+    }
+    Ok(())
+  }
+
+  pub(crate) fn apply_to_connection(
+    &self,
+    socket: &Socket,
+  ) -> std::io::Result<()> {
+    socket.set_nodelay(self.no_delay)?;
+    match self.keep_alive {
+      Some(interval) => {
+        socket.set_tcp_keepalive(&TcpKeepalive::new().with_time(interval))
+      }
+      None => socket.set_keepalive(false),
+    }
+  }
+}
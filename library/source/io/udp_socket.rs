@@ -0,0 +1,113 @@
+use std::net::SocketAddr;
+use std::net::ToSocketAddrs;
+
+use socket2::Domain;
+use socket2::Protocol;
+use socket2::Socket;
+use socket2::Type;
+
+use crate::io::buffer::*;
+use crate::io::connection::*;
+use crate::io::scheduled_socket::*;
+
+pub const MAX_DATAGRAM_SIZE: usize = 65536;
+
+pub struct UdpSocket {
+  socket: ScheduledSocket,
+}
+
+impl UdpSocket {
+  pub fn bind(
+    address: impl ToSocketAddrs + Send + 'static,
+  ) -> std::io::Result<Self> {
+    let mut last_error = None;
+    for address in resolve(address)? {
+      match UdpSocket::bind_address(address, false) {
+        Ok(socket) => return Ok(socket),
+        Err(error) => last_error = Some(error),
+      }
+    }
+    Err(last_error.unwrap_or_else(|| {
+      std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "No addresses to bind to.",
+      )
+    }))
+  }
+
+  pub(crate) fn bind_address(
+    address: SocketAddr,
+    is_shared: bool,
+  ) -> std::io::Result<Self> {
+    let socket = Socket::new(
+      Domain::for_address(address),
+      Type::DGRAM,
+      Some(Protocol::UDP),
+    )?;
+    if is_shared {
+      socket.set_reuse_address(true)?;
+      socket.set_reuse_port(true)?;
+    }
+    socket.bind(&address.into())?;
+    Ok(UdpSocket {
+      socket: ScheduledSocket::new(socket)?,
+    })
+  }
+
+  pub(crate) fn socket(&self) -> &Socket {
+    self.socket.socket()
+  }
+
+  pub fn local_address(&self) -> std::io::Result<SocketAddr> {
+    to_socket_address(self.socket().local_addr()?.as_socket())
+  }
+
+  pub fn set_broadcast(&self, is_enabled: bool) -> std::io::Result<()> {
+    self.socket().set_broadcast(is_enabled)
+  }
+
+  pub fn set_send_buffer_size(&self, size: usize) -> std::io::Result<()> {
+    self.socket().set_send_buffer_size(size)
+  }
+
+  pub fn set_receive_buffer_size(&self, size: usize) -> std::io::Result<()> {
+    self.socket().set_recv_buffer_size(size)
+  }
+
+  pub fn send_to(
+    &self,
+    data: &[u8],
+    address: SocketAddr,
+  ) -> std::io::Result<usize> {
+    self.socket.send_to(data, &address.into())
+  }
+
+  pub fn receive_from(
+    &self,
+    buffer: &mut Buffer,
+  ) -> std::io::Result<(usize, SocketAddr)> {
+    let (size, address) =
+      self.socket.receive_from(buffer, MAX_DATAGRAM_SIZE)?;
+    Ok((size, to_socket_address(address.as_socket())?))
+  }
+}
+
+impl Connection for UdpSocket {
+  fn close(&self) -> std::io::Result<()> {
+    self.socket.close()
+  }
+}
+
+impl Drop for UdpSocket {
+  fn drop(&mut self) {
+    let _ = self.close();
+  }
+}
+
+fn to_socket_address(
+  address: Option<SocketAddr>,
+) -> std::io::Result<SocketAddr> {
+  address.ok_or_else(|| {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, "Not an IP address.")
+  })
+}
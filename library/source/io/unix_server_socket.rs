@@ -0,0 +1,74 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use socket2::Domain;
+use socket2::Socket;
+use socket2::Type;
+
+use crate::io::connection::*;
+use crate::io::scheduled_socket::*;
+use crate::io::server_connection::*;
+use crate::io::unix_socket_address::*;
+use crate::io::unix_socket_channel::*;
+use crate::io::unix_socket_identifier::*;
+
+const DEFAULT_BACKLOG: i32 = 1024;
+
+pub struct UnixServerSocket {
+  address: UnixSocketAddress,
+  socket: ScheduledSocket,
+  next_connection: AtomicU64,
+  is_closed: AtomicBool,
+}
+
+impl UnixServerSocket {
+  pub fn bind(address: impl Into<UnixSocketAddress>) -> std::io::Result<Self> {
+    let address = address.into();
+    let socket = Socket::new(Domain::UNIX, Type::STREAM, None)?;
+    socket.bind(&address.to_sock_addr()?)?;
+    socket.listen(DEFAULT_BACKLOG)?;
+    Ok(UnixServerSocket {
+      address,
+      socket: ScheduledSocket::new(socket)?,
+      next_connection: AtomicU64::new(0),
+      is_closed: AtomicBool::new(false),
+    })
+  }
+
+  pub fn address(&self) -> &UnixSocketAddress {
+    &self.address
+  }
+}
+
+impl Connection for UnixServerSocket {
+  fn close(&self) -> std::io::Result<()> {
+    if self.is_closed.swap(true, Ordering::AcqRel) {
+      return Ok(());
+    }
+    let result = self.socket.close();
+    if let UnixSocketAddress::Path(path) = &self.address {
+      let _ = std::fs::remove_file(path);
+    }
+    result
+  }
+}
+
+impl ServerConnection for UnixServerSocket {
+  type Channel = UnixSocketChannel;
+
+  fn accept(&self) -> std::io::Result<UnixSocketChannel> {
+    let (socket, _) = self.socket.accept()?;
+    let connection = self.next_connection.fetch_add(1, Ordering::Relaxed);
+    UnixSocketChannel::from_socket(
+      socket,
+      UnixSocketIdentifier::with_connection(self.address.clone(), connection),
+    )
+  }
+}
+
+impl Drop for UnixServerSocket {
+  fn drop(&mut self) {
+    let _ = self.close();
+  }
+}
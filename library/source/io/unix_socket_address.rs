@@ -0,0 +1,52 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+use socket2::SockAddr;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum UnixSocketAddress {
+  Path(PathBuf),
+  Abstract(Vec<u8>),
+}
+
+impl UnixSocketAddress {
+  pub(crate) fn to_sock_addr(&self) -> std::io::Result<SockAddr> {
+    match self {
+      UnixSocketAddress::Path(path) => SockAddr::unix(path),
+      UnixSocketAddress::Abstract(name) => {
+        let mut bytes = Vec::with_capacity(name.len() + 1);
+        bytes.push(0);
+        bytes.extend_from_slice(name);
+        SockAddr::unix(OsStr::from_bytes(&bytes))
+      }
+    }
+  }
+}
+
+impl fmt::Display for UnixSocketAddress {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      UnixSocketAddress::Path(path) => write!(f, "{}", path.display()),
+      UnixSocketAddress::Abstract(name) => {
+        write!(f, "@{}", String::from_utf8_lossy(name))
+      }
+    }
+  }
+}
+
+impl From<PathBuf> for UnixSocketAddress {
+  fn from(path: PathBuf) -> Self {
+    UnixSocketAddress::Path(path)
+  }
+}
+
+impl From<&str> for UnixSocketAddress {
+  fn from(path: &str) -> Self {
+    match path.strip_prefix('@') {
+      Some(name) => UnixSocketAddress::Abstract(name.as_bytes().to_vec()),
+      None => UnixSocketAddress::Path(PathBuf::from(path)),
+    }
+  }
+}
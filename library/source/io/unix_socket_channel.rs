@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use socket2::Domain;
+use socket2::Socket;
+use socket2::Type;
+
+use crate::io::channel::*;
+use crate::io::connection::*;
+use crate::io::peer_credentials::*;
+use crate::io::scheduled_socket::*;
+use crate::io::socket_connection::*;
+use crate::io::socket_reader::*;
+use crate::io::socket_writer::*;
+use crate::io::unix_socket_address::*;
+use crate::io::unix_socket_identifier::*;
+
+pub struct UnixSocketChannel {
+  identifier: UnixSocketIdentifier,
+  connection: SocketConnection,
+  reader: SocketReader,
+  writer: SocketWriter,
+}
+
+impl UnixSocketChannel {
+  pub fn connect(
+    address: impl Into<UnixSocketAddress>,
+  ) -> std::io::Result<Self> {
+    let address = address.into();
+    let socket = Socket::new(Domain::UNIX, Type::STREAM, None)?;
+    let channel = UnixSocketChannel::from_socket(
+      socket,
+      UnixSocketIdentifier::new(address.clone()),
+    )?;
+    channel.reader.socket().connect(&address.to_sock_addr()?)?;
+    Ok(channel)
+  }
+
+  pub(crate) fn from_socket(
+    socket: Socket,
+    identifier: UnixSocketIdentifier,
+  ) -> std::io::Result<Self> {
+    let socket = Arc::new(ScheduledSocket::new(socket)?);
+    Ok(UnixSocketChannel {
+      identifier,
+      connection: SocketConnection::new(socket.clone()),
+      reader: SocketReader::new(socket.clone()),
+      writer: SocketWriter::new(socket),
+    })
+  }
+
+  pub fn peer_credentials(&self) -> std::io::Result<PeerCredentials> {
+    PeerCredentials::from_fd(self.reader.socket().fd())
+  }
+}
+
+impl Channel for UnixSocketChannel {
+  type Identifier = UnixSocketIdentifier;
+  type Connection = SocketConnection;
+  type Reader = SocketReader;
+  type Writer = SocketWriter;
+
+  fn identifier(&self) -> &UnixSocketIdentifier {
+    &self.identifier
+  }
+
+  fn connection(&self) -> &SocketConnection {
+    &self.connection
+  }
+
+  fn reader(&self) -> &SocketReader {
+    &self.reader
+  }
+
+  fn writer(&self) -> &SocketWriter {
+    &self.writer
+  }
+}
+
+impl Drop for UnixSocketChannel {
+  fn drop(&mut self) {
+    let _ = self.connection.close();
+  }
+}
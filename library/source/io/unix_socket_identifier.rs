@@ -0,0 +1,42 @@
+use std::fmt;
+
+use crate::io::unix_socket_address::*;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UnixSocketIdentifier {
+  address: UnixSocketAddress,
+  connection: Option<u64>,
+}
+
+impl UnixSocketIdentifier {
+  pub fn new(address: UnixSocketAddress) -> Self {
+    UnixSocketIdentifier {
+      address,
+      connection: None,
+    }
+  }
+
+  pub fn with_connection(address: UnixSocketAddress, connection: u64) -> Self {
+    UnixSocketIdentifier {
+      address,
+      connection: Some(connection),
+    }
+  }
+
+  pub fn address(&self) -> &UnixSocketAddress {
+    &self.address
+  }
+
+  pub fn connection(&self) -> Option<u64> {
+    self.connection
+  }
+}
+
+impl fmt::Display for UnixSocketIdentifier {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.connection {
+      Some(connection) => write!(f, "{}#{}", self.address, connection),
+      None => write!(f, "{}", self.address),
+    }
+  }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::io::buffer::*;
+
+pub trait Writer: Send + Sync {
+  fn write(&self, data: &Buffer) -> std::io::Result<()>;
+}
+
+impl<T: Writer + ?Sized> Writer for &T {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    (**self).write(data)
+  }
+}
+
+impl<T: Writer + ?Sized> Writer for Box<T> {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    (**self).write(data)
+  }
+}
+
+impl<T: Writer + ?Sized> Writer for Arc<T> {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    (**self).write(data)
+  }
+}
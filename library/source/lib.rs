@@ -1,2 +1,10 @@
+extern crate self as beam;
+
+pub mod codecs;
+pub mod io;
 pub mod routines;
+pub mod serialization;
 pub mod service_locator;
+pub mod services;
+pub mod threading;
+pub mod time_service;
@@ -0,0 +1,96 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::panic::AssertUnwindSafe;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::routines::future::*;
+use crate::routines::promise::*;
+
+const MAX_THREAD_COUNT: usize = 512;
+const KEEP_ALIVE: Duration = Duration::from_secs(10);
+
+type BlockingTask = Box<dyn FnOnce() + Send>;
+
+struct BlockingPoolData {
+  tasks: VecDeque<BlockingTask>,
+  thread_count: usize,
+  idle_count: usize,
+}
+
+pub(crate) struct BlockingPool {
+  data: Mutex<BlockingPoolData>,
+  tasks_available: Condvar,
+}
+
+impl BlockingPool {
+  fn new() -> Self {
+    BlockingPool {
+      data: Mutex::new(BlockingPoolData {
+        tasks: VecDeque::new(),
+        thread_count: 0,
+        idle_count: 0,
+      }),
+      tasks_available: Condvar::new(),
+    }
+  }
+
+  pub(crate) fn submit(&'static self, task: BlockingTask) {
+    let mut data = self.data.lock().unwrap();
+    data.tasks.push_back(task);
+    if data.tasks.len() > data.idle_count
+      && data.thread_count < MAX_THREAD_COUNT
+    {
+      data.thread_count += 1;
+      std::thread::Builder::new()
+        .name(String::from("beam-blocking"))
+        .spawn(move || self.run())
+        .unwrap();
+    }
+    self.tasks_available.notify_one();
+  }
+
+  fn run(&self) {
+    let mut data = self.data.lock().unwrap();
+    loop {
+      if let Some(task) = data.tasks.pop_front() {
+        drop(data);
+        task();
+        data = self.data.lock().unwrap();
+        continue;
+      }
+      data.idle_count += 1;
+      let (next_data, timeout) =
+        self.tasks_available.wait_timeout(data, KEEP_ALIVE).unwrap();
+      data = next_data;
+      data.idle_count -= 1;
+      if timeout.timed_out() && data.tasks.is_empty() {
+        data.thread_count -= 1;
+        return;
+      }
+    }
+  }
+}
+
+static BLOCKING_POOL: OnceLock<BlockingPool> = OnceLock::new();
+
+pub(crate) fn get_blocking_pool() -> &'static BlockingPool {
+  BLOCKING_POOL.get_or_init(BlockingPool::new)
+}
+
+pub fn spawn_blocking<T, F>(f: F) -> Future<T, Box<dyn Any + Send>>
+where
+  T: Send + 'static,
+  F: FnOnce() -> T + Send + 'static,
+{
+  let (promise, future) = Promise::new_link();
+  get_blocking_pool().submit(Box::new(move || match std::panic::catch_unwind(
+    AssertUnwindSafe(f),
+  ) {
+    Ok(result) => promise.resolve(result),
+    Err(payload) => promise.reject(payload),
+  }));
+  future
+}
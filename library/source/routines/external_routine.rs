@@ -1,8 +1,8 @@
 use std::sync::atomic::Ordering;
 use std::sync::Condvar;
 use std::sync::Mutex;
+use std::time::Duration;
 
-use crate::routines::promise::*;
 use crate::routines::routine::*;
 
 pub(crate) struct ExternalRoutine {
@@ -10,7 +10,7 @@ pub(crate) struct ExternalRoutine {
   id: u64,
   is_pending_resume: Mutex<bool>,
   suspended_condition: Condvar,
-  wait_promises: Mutex<Vec<Promise<(), ()>>>,
+  wait_callbacks: Mutex<Vec<WaitCallback>>,
 }
 
 impl ExternalRoutine {
@@ -20,7 +20,7 @@ impl ExternalRoutine {
       id: ROUTINE_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
       is_pending_resume: Mutex::new(false),
       suspended_condition: Condvar::new(),
-      wait_promises: Mutex::new(Vec::new()),
+      wait_callbacks: Mutex::new(Vec::new()),
     }
   }
 }
@@ -44,9 +44,31 @@ impl Routine for ExternalRoutine {
 
   fn set_pending_resume(&mut self, _: bool) {}
 
-  fn wait(&mut self, result: Promise<(), ()>) {
-    let mut wait_promises = self.wait_promises.lock().unwrap();
-    wait_promises.push(result);
+  fn is_cancelled(&self) -> bool {
+    false
+  }
+
+  fn cancel(&self) -> Option<CancelHandler> {
+    None
+  }
+
+  fn set_cancel_handler(&self, _: Option<CancelHandler>) -> bool {
+    true
+  }
+
+  fn priority(&self) -> RoutinePriority {
+    RoutinePriority::Normal
+  }
+
+  fn set_priority(&self, _: RoutinePriority) {}
+
+  fn slice_elapsed(&self) -> Duration {
+    Duration::ZERO
+  }
+
+  fn wait(&mut self, callback: WaitCallback) {
+    let mut wait_callbacks = self.wait_callbacks.lock().unwrap();
+    wait_callbacks.push(callback);
   }
 
   fn defer(&mut self) {}
@@ -88,10 +110,10 @@ impl Routine for ExternalRoutine {
 impl Drop for ExternalRoutine {
   fn drop(&mut self) {
     self.state = RoutineState::Complete;
-    let mut lock = self.wait_promises.lock().unwrap();
-    let wait_promises = std::mem::take(&mut *lock);
-    for promise in wait_promises.into_iter() {
-      promise.resolve(());
+    let mut lock = self.wait_callbacks.lock().unwrap();
+    let wait_callbacks = std::mem::take(&mut *lock);
+    for callback in wait_callbacks.into_iter() {
+      callback(RoutineStatus::Complete);
     }
   }
 }
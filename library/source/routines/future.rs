@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::routines::routine::current_routine;
 use crate::routines::suspended_routine_queue::*;
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -16,6 +17,12 @@ pub(crate) struct FutureData<T, E> {
   pub(crate) result: Option<Result<T, E>>,
 }
 
+// SAFETY: The suspended routine pointers are only touched while the
+// surrounding Mutex is held, and resuming them goes through the scheduler,
+// which locks the owning context before rescheduling, so the data may be
+// resolved from any thread.
+unsafe impl<T: Send, E: Send> Send for FutureData<T, E> {}
+
 impl<T, E> FutureData<T, E> {
   pub fn set_state(&mut self, state: FutureState) {
     assert!(self.state == FutureState::Pending);
@@ -52,6 +59,30 @@ impl<T, E> Future<T, E> {
     return std::mem::replace(&mut data.result, None).unwrap();
   }
 
+  pub fn cancellable_result(self) -> Option<Result<T, E>>
+  where
+    T: Send + 'static,
+    E: Send + 'static,
+  {
+    let routine = current_routine();
+    let data = self.data.clone();
+    if !routine.set_cancel_handler(Some(Box::new(move || {
+      resume(&mut data.lock().unwrap().suspended_routines);
+    }))) {
+      return None;
+    }
+    let mut data = self.data.lock().unwrap();
+    while data.state == FutureState::Pending && !routine.is_cancelled() {
+      let suspended_routines = &mut data.suspended_routines as *mut _;
+      suspend(unsafe { &mut *suspended_routines }, data);
+      data = self.data.lock().unwrap();
+    }
+    let result = data.result.take();
+    drop(data);
+    routine.set_cancel_handler(None);
+    result
+  }
+
   pub fn state(&self) -> FutureState {
     self.data.lock().unwrap().state
   }
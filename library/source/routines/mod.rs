@@ -1,12 +1,21 @@
+mod blocking_pool;
 mod external_routine;
 mod future;
+mod pending_routine_queue;
 mod promise;
 mod routine;
+mod routine_group;
 mod scheduled_routine;
 mod scheduler;
-mod suspended_routine_queue;
+mod scheduler_metrics;
+pub(crate) mod suspended_routine_queue;
+mod task_queue;
 
+pub use blocking_pool::*;
 pub use future::*;
 pub use promise::*;
 pub use routine::*;
+pub use routine_group::*;
 pub use scheduler::*;
+pub use scheduler_metrics::*;
+pub use task_queue::*;
@@ -0,0 +1,52 @@
+use std::collections::VecDeque;
+
+use crate::routines::routine::*;
+
+const PRIORITY_COUNT: usize = 3;
+const STARVATION_LIMIT: usize = 8;
+
+pub(crate) struct PendingRoutineQueue {
+  routines: [VecDeque<*mut dyn Routine>; PRIORITY_COUNT],
+  skip_counts: [usize; PRIORITY_COUNT],
+}
+
+impl PendingRoutineQueue {
+  pub(crate) fn new() -> Self {
+    PendingRoutineQueue {
+      routines: [VecDeque::new(), VecDeque::new(), VecDeque::new()],
+      skip_counts: [0; PRIORITY_COUNT],
+    }
+  }
+
+  pub(crate) fn len(&self) -> usize {
+    self.routines.iter().map(|routines| routines.len()).sum()
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    self.routines.iter().all(|routines| routines.is_empty())
+  }
+
+  pub(crate) fn push_back(&mut self, routine: *mut dyn Routine) {
+    let priority = unsafe { (*routine).priority() } as usize;
+    self.routines[priority].push_back(routine);
+  }
+
+  pub(crate) fn pop_front(&mut self) -> Option<*mut dyn Routine> {
+    let highest = (0..PRIORITY_COUNT)
+      .rev()
+      .find(|&priority| !self.routines[priority].is_empty())?;
+    let selected = (0..highest)
+      .find(|&priority| {
+        !self.routines[priority].is_empty()
+          && self.skip_counts[priority] >= STARVATION_LIMIT
+      })
+      .unwrap_or(highest);
+    for priority in 0..highest {
+      if priority != selected && !self.routines[priority].is_empty() {
+        self.skip_counts[priority] += 1;
+      }
+    }
+    self.skip_counts[selected] = 0;
+    self.routines[selected].pop_front()
+  }
+}
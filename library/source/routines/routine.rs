@@ -1,9 +1,9 @@
 use std::cell::RefCell;
 use std::sync::atomic::AtomicU64;
 use std::thread_local;
+use std::time::Duration;
 
 use crate::routines::external_routine::*;
-use crate::routines::promise::*;
 use crate::routines::scheduler::*;
 
 pub(crate) static ROUTINE_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -22,6 +22,36 @@ pub enum RoutineState {
   Complete,
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum RoutinePriority {
+  Low,
+  #[default]
+  Normal,
+  High,
+}
+
+impl From<u8> for RoutinePriority {
+  fn from(value: u8) -> Self {
+    match value {
+      0 => RoutinePriority::Low,
+      1 => RoutinePriority::Normal,
+      _ => RoutinePriority::High,
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum RoutineStatus {
+  NotFound,
+  Complete,
+  Panicked,
+  Cancelled,
+}
+
+pub(crate) type WaitCallback = Box<dyn FnOnce(RoutineStatus)>;
+
+pub(crate) type CancelHandler = Box<dyn FnOnce() + Send>;
+
 pub(crate) trait Routine {
   fn id(&self) -> u64;
 
@@ -33,7 +63,19 @@ pub(crate) trait Routine {
 
   fn set_pending_resume(&mut self, is_pending_resume: bool);
 
-  fn wait(&mut self, result: Promise<(), ()>);
+  fn is_cancelled(&self) -> bool;
+
+  fn cancel(&self) -> Option<CancelHandler>;
+
+  fn set_cancel_handler(&self, handler: Option<CancelHandler>) -> bool;
+
+  fn priority(&self) -> RoutinePriority;
+
+  fn set_priority(&self, priority: RoutinePriority);
+
+  fn slice_elapsed(&self) -> Duration;
+
+  fn wait(&mut self, callback: WaitCallback);
 
   fn defer(&mut self);
 
@@ -63,8 +105,43 @@ pub fn defer() {
   current_routine().defer();
 }
 
-pub fn wait(routine: u64) {
-  get_scheduler().wait(routine);
+pub fn maybe_yield() {
+  let routine = current_routine();
+  if routine.slice_elapsed() >= get_scheduler().yield_slice() {
+    routine.defer();
+  }
+}
+
+pub fn wait(routine: u64) -> RoutineStatus {
+  get_scheduler().wait(routine)
+}
+
+pub fn wait_all(routines: &[u64]) -> Vec<RoutineStatus> {
+  routines.iter().map(|routine| wait(*routine)).collect()
+}
+
+pub fn wait_any(routines: &[u64]) -> (u64, RoutineStatus) {
+  get_scheduler().wait_any(routines)
+}
+
+pub fn cancel(routine: u64) {
+  get_scheduler().cancel(routine);
+}
+
+pub fn is_cancelled() -> bool {
+  current_routine().is_cancelled()
+}
+
+pub fn current_id() -> u64 {
+  current_routine().id()
+}
+
+pub fn priority() -> RoutinePriority {
+  current_routine().priority()
+}
+
+pub fn set_priority(routine: u64, priority: RoutinePriority) {
+  get_scheduler().set_priority(routine, priority);
 }
 
 pub(crate) fn suspend() {
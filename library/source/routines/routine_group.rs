@@ -0,0 +1,175 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::routines::routine::*;
+use crate::routines::scheduler::*;
+
+enum RoutineFailure<E> {
+  Error(E),
+  Panic(Box<dyn Any + Send>),
+}
+
+struct RoutineGroupData<E> {
+  routines: Vec<u64>,
+  failure: Option<RoutineFailure<E>>,
+  is_cancelled: bool,
+}
+
+pub struct RoutineGroup<E: 'static> {
+  data: Arc<Mutex<RoutineGroupData<E>>>,
+}
+
+impl<E: 'static> RoutineGroup<E> {
+  pub fn new() -> Self {
+    RoutineGroup {
+      data: Arc::new(Mutex::new(RoutineGroupData {
+        routines: Vec::new(),
+        failure: None,
+        is_cancelled: false,
+      })),
+    }
+  }
+
+  pub fn spawn<F: FnOnce() -> Result<(), E> + 'static>(&self, f: F) -> u64 {
+    let data = self.data.clone();
+    let mut group = self.data.lock().unwrap();
+    let id = spawn(move || {
+      let failure = match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => return,
+        Ok(Err(error)) => RoutineFailure::Error(error),
+        Err(payload) => RoutineFailure::Panic(payload),
+      };
+      fail(&data, failure);
+    });
+    group.routines.push(id);
+    if group.is_cancelled {
+      cancel(id);
+    }
+    id
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.data.lock().unwrap().is_cancelled
+  }
+
+  pub fn cancel(&self) {
+    let routines = {
+      let mut group = self.data.lock().unwrap();
+      group.is_cancelled = true;
+      group.routines.clone()
+    };
+    for routine in routines {
+      cancel(routine);
+    }
+  }
+
+  pub fn wait_all(&self) -> Result<(), E> {
+    match self.wait() {
+      None => Ok(()),
+      Some(RoutineFailure::Error(error)) => Err(error),
+      Some(RoutineFailure::Panic(payload)) => {
+        std::panic::resume_unwind(payload)
+      }
+    }
+  }
+
+  fn wait(&self) -> Option<RoutineFailure<E>> {
+    let mut index = 0;
+    loop {
+      let routine = match self.data.lock().unwrap().routines.get(index) {
+        Some(routine) => *routine,
+        None => break,
+      };
+      wait(routine);
+      index += 1;
+    }
+    let mut group = self.data.lock().unwrap();
+    group.routines.clear();
+    group.is_cancelled = false;
+    group.failure.take()
+  }
+}
+
+impl<E: 'static> Default for RoutineGroup<E> {
+  fn default() -> Self {
+    RoutineGroup::new()
+  }
+}
+
+impl<E: 'static> Drop for RoutineGroup<E> {
+  fn drop(&mut self) {
+    if let Some(RoutineFailure::Panic(payload)) = self.wait() {
+      if !std::thread::panicking() {
+        std::panic::resume_unwind(payload);
+      }
+    }
+  }
+}
+
+fn fail<E>(data: &Mutex<RoutineGroupData<E>>, failure: RoutineFailure<E>) {
+  let routines = {
+    let mut group = data.lock().unwrap();
+    if group.failure.is_none() {
+      group.failure = Some(failure);
+    }
+    group.is_cancelled = true;
+    group.routines.clone()
+  };
+  for routine in routines {
+    cancel(routine);
+  }
+}
+
+pub struct RoutineScope<'scope, 'env: 'scope, E: 'static> {
+  group: RoutineGroup<E>,
+  scope: PhantomData<&'scope mut &'scope ()>,
+  env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, E: 'static> RoutineScope<'scope, '_, E> {
+  pub fn spawn<F: FnOnce() -> Result<(), E> + 'scope>(
+    &'scope self,
+    f: F,
+  ) -> u64 {
+    let f: Box<dyn FnOnce() -> Result<(), E> + 'scope> = Box::new(f);
+    let f = unsafe {
+      std::mem::transmute::<
+        Box<dyn FnOnce() -> Result<(), E> + 'scope>,
+        Box<dyn FnOnce() -> Result<(), E> + 'static>,
+      >(f)
+    };
+    self.group.spawn(f)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.group.is_cancelled()
+  }
+
+  pub fn cancel(&self) {
+    self.group.cancel();
+  }
+}
+
+pub fn scope<'env, E: 'static, F, T>(f: F) -> Result<T, E>
+where
+  F: for<'scope> FnOnce(&'scope RoutineScope<'scope, 'env, E>) -> T,
+{
+  let scope = RoutineScope {
+    group: RoutineGroup::new(),
+    scope: PhantomData,
+    env: PhantomData,
+  };
+  let result = std::panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+  let failure = scope.group.wait();
+  match (result, failure) {
+    (Err(payload), _) => std::panic::resume_unwind(payload),
+    (Ok(_), Some(RoutineFailure::Panic(payload))) => {
+      std::panic::resume_unwind(payload)
+    }
+    (Ok(_), Some(RoutineFailure::Error(error))) => Err(error),
+    (Ok(value), None) => Ok(value),
+  }
+}
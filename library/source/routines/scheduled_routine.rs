@@ -1,23 +1,34 @@
 use std::mem::MaybeUninit;
+use std::panic::AssertUnwindSafe;
 use std::ptr::addr_of_mut;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU8;
 use std::sync::atomic::Ordering;
 use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
 
 use corosensei::stack::DefaultStack;
 use corosensei::Coroutine;
 use corosensei::Yielder;
 
-use crate::routines::promise::*;
 use crate::routines::routine::*;
 use crate::routines::scheduler::*;
 
 pub(crate) struct ScheduledRoutine {
   state: RoutineState,
   id: u64,
-  wait_promises: Mutex<Vec<Promise<(), ()>>>,
+  wait_callbacks: Mutex<Vec<WaitCallback>>,
   is_pending_resume: bool,
+  is_panicked: bool,
+  is_cancelled: AtomicBool,
+  cancel_handler: Mutex<Option<CancelHandler>>,
+  priority: AtomicU8,
+  advance_time: Instant,
   context_id: usize,
   function: Option<Coroutine<(), (), ()>>,
+  #[cfg(feature = "tracing")]
+  span: tracing::Span,
   yielder: *const Yielder<(), ()>,
 }
 
@@ -26,6 +37,7 @@ impl ScheduledRoutine {
     f: F,
     stack_size: usize,
     mut context_id: usize,
+    priority: RoutinePriority,
   ) -> Box<Self>
   where
     F: 'static,
@@ -39,14 +51,29 @@ impl ScheduledRoutine {
     unsafe {
       addr_of_mut!((*routine).state).write(RoutineState::Pending);
       addr_of_mut!((*routine).id).write(id);
-      addr_of_mut!((*routine).wait_promises).write(Mutex::new(Vec::new()));
+      addr_of_mut!((*routine).wait_callbacks).write(Mutex::new(Vec::new()));
       addr_of_mut!((*routine).is_pending_resume).write(false);
+      addr_of_mut!((*routine).is_panicked).write(false);
+      addr_of_mut!((*routine).is_cancelled).write(AtomicBool::new(false));
+      addr_of_mut!((*routine).cancel_handler).write(Mutex::new(None));
+      addr_of_mut!((*routine).priority).write(AtomicU8::new(priority as u8));
+      addr_of_mut!((*routine).advance_time).write(Instant::now());
       addr_of_mut!((*routine).context_id).write(context_id);
+      #[cfg(feature = "tracing")]
+      addr_of_mut!((*routine).span).write(tracing::info_span!(
+        parent: tracing::Span::current(),
+        "routine",
+        id
+      ));
       addr_of_mut!((*routine).function).write(Some(Coroutine::with_stack(
         DefaultStack::new(stack_size).unwrap(),
         move |yielder, _| {
           (*routine).yielder = yielder as *const Yielder<(), ()>;
-          f();
+          if !(*routine).is_cancelled()
+            && std::panic::catch_unwind(AssertUnwindSafe(f)).is_err()
+          {
+            (*routine).is_panicked = true;
+          }
           (*routine).set_state(RoutineState::Complete);
         },
       )));
@@ -76,9 +103,39 @@ impl Routine for ScheduledRoutine {
     self.is_pending_resume = is_pending_resume;
   }
 
-  fn wait(&mut self, result: Promise<(), ()>) {
-    let mut wait_promises = self.wait_promises.lock().unwrap();
-    wait_promises.push(result);
+  fn is_cancelled(&self) -> bool {
+    self.is_cancelled.load(Ordering::SeqCst)
+  }
+
+  fn cancel(&self) -> Option<CancelHandler> {
+    self.is_cancelled.store(true, Ordering::SeqCst);
+    self.cancel_handler.lock().unwrap().take()
+  }
+
+  fn set_cancel_handler(&self, handler: Option<CancelHandler>) -> bool {
+    let mut cancel_handler = self.cancel_handler.lock().unwrap();
+    if handler.is_some() && self.is_cancelled() {
+      return false;
+    }
+    *cancel_handler = handler;
+    true
+  }
+
+  fn priority(&self) -> RoutinePriority {
+    RoutinePriority::from(self.priority.load(Ordering::SeqCst))
+  }
+
+  fn set_priority(&self, priority: RoutinePriority) {
+    self.priority.store(priority as u8, Ordering::SeqCst);
+  }
+
+  fn slice_elapsed(&self) -> Duration {
+    self.advance_time.elapsed()
+  }
+
+  fn wait(&mut self, callback: WaitCallback) {
+    let mut wait_callbacks = self.wait_callbacks.lock().unwrap();
+    wait_callbacks.push(callback);
   }
 
   fn defer(&mut self) {
@@ -113,6 +170,9 @@ impl Routine for ScheduledRoutine {
     });
     self.is_pending_resume = false;
     self.set_state(RoutineState::Running);
+    self.advance_time = Instant::now();
+    #[cfg(feature = "tracing")]
+    let _entered = self.span.enter();
     self.function.as_mut().unwrap().resume(());
     CURRENT_ROUTINE.with(|routine_cell| {
       let mut routine = routine_cell.borrow_mut();
@@ -128,10 +188,17 @@ impl Routine for ScheduledRoutine {
 impl Drop for ScheduledRoutine {
   fn drop(&mut self) {
     self.state = RoutineState::Complete;
-    let mut lock = self.wait_promises.lock().unwrap();
-    let wait_promises = std::mem::take(&mut *lock);
-    for promise in wait_promises.into_iter() {
-      promise.resolve(());
+    let status = if self.is_panicked {
+      RoutineStatus::Panicked
+    } else if self.is_cancelled() {
+      RoutineStatus::Cancelled
+    } else {
+      RoutineStatus::Complete
+    };
+    let mut lock = self.wait_callbacks.lock().unwrap();
+    let wait_callbacks = std::mem::take(&mut *lock);
+    for callback in wait_callbacks.into_iter() {
+      callback(status);
     }
   }
 }
@@ -1,23 +1,32 @@
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::collections::VecDeque;
 use std::mem::MaybeUninit;
 use std::num::NonZero;
 use std::ptr::addr_of_mut;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::sync::Condvar;
 use std::sync::Mutex;
 use std::sync::MutexGuard;
 use std::sync::Once;
+use std::sync::RwLock;
 use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
 
+use crate::routines::pending_routine_queue::*;
 use crate::routines::promise::*;
 use crate::routines::routine::*;
 use crate::routines::scheduled_routine::*;
+use crate::routines::scheduler_metrics::*;
+
+const DEFAULT_YIELD_SLICE: Duration = Duration::from_millis(10);
+const OVERRUN_FACTOR: u32 = 10;
 
 struct Context {
   is_running: bool,
-  pending_routines: VecDeque<*mut dyn Routine>,
-  suspended_routines: HashSet<*mut dyn Routine>,
+  pending_routines: PendingRoutineQueue,
+  suspended_routines: HashMap<*const (), *mut dyn Routine>,
   pending_routines_available: Condvar,
 }
 
@@ -25,8 +34,8 @@ impl Context {
   fn new() -> Self {
     Context {
       is_running: true,
-      pending_routines: VecDeque::new(),
-      suspended_routines: HashSet::new(),
+      pending_routines: PendingRoutineQueue::new(),
+      suspended_routines: HashMap::new(),
       pending_routines_available: Condvar::new(),
     }
   }
@@ -36,6 +45,11 @@ pub(crate) struct Scheduler {
   thread_count: usize,
   routine_ids: Mutex<HashMap<u64, *mut dyn Routine>>,
   contexts: Box<[Mutex<Context>]>,
+  context_counters: Box<[ContextCounters]>,
+  spawn_count: AtomicU64,
+  complete_count: AtomicU64,
+  hooks: RwLock<Option<Arc<dyn SchedulerHooks>>>,
+  yield_slice: AtomicU64,
   threads: Box<[Option<JoinHandle<()>>]>,
 }
 
@@ -51,16 +65,25 @@ impl Scheduler {
       );
       addr_of_mut!((*scheduler).routine_ids).write(Mutex::new(HashMap::new()));
       let mut contexts = Vec::new();
+      let mut context_counters = Vec::new();
       for _ in 0..(*scheduler).thread_count {
         contexts.push(Mutex::new(Context::new()));
+        context_counters.push(ContextCounters::default());
       }
       addr_of_mut!((*scheduler).contexts).write(contexts.into_boxed_slice());
+      addr_of_mut!((*scheduler).context_counters)
+        .write(context_counters.into_boxed_slice());
+      addr_of_mut!((*scheduler).spawn_count).write(AtomicU64::new(0));
+      addr_of_mut!((*scheduler).complete_count).write(AtomicU64::new(0));
+      addr_of_mut!((*scheduler).hooks).write(RwLock::new(None));
+      addr_of_mut!((*scheduler).yield_slice)
+        .write(AtomicU64::new(to_nanos(DEFAULT_YIELD_SLICE)));
       let scheduler_ptr = scheduler as usize;
       let mut threads = Vec::new();
       for i in 0..(*scheduler).thread_count {
         threads.push(Some(std::thread::spawn(move || {
           let scheduler = scheduler_ptr as *mut Scheduler;
-          (*scheduler).run(&mut (*scheduler).contexts[i]);
+          (*scheduler).run(i);
         })));
       }
       addr_of_mut!((*scheduler).threads).write(threads.into_boxed_slice());
@@ -74,6 +97,44 @@ impl Scheduler {
     self.thread_count
   }
 
+  pub(crate) fn metrics(&self) -> SchedulerMetrics {
+    let mut contexts = Vec::with_capacity(self.thread_count);
+    for i in 0..self.thread_count {
+      let (pending_routines, suspended_routines) = {
+        let context = self.contexts[i].lock().unwrap();
+        (
+          context.pending_routines.len(),
+          context.suspended_routines.len(),
+        )
+      };
+      contexts.push(
+        self.context_counters[i].load(pending_routines, suspended_routines),
+      );
+    }
+    SchedulerMetrics {
+      timestamp: Instant::now(),
+      spawn_count: self.spawn_count.load(Ordering::Relaxed),
+      complete_count: self.complete_count.load(Ordering::Relaxed),
+      contexts,
+    }
+  }
+
+  pub(crate) fn set_hooks(&self, hooks: Option<Arc<dyn SchedulerHooks>>) {
+    *self.hooks.write().unwrap() = hooks;
+  }
+
+  pub(crate) fn yield_slice(&self) -> Duration {
+    Duration::from_nanos(self.yield_slice.load(Ordering::Relaxed))
+  }
+
+  pub(crate) fn set_yield_slice(&self, slice: Duration) {
+    self.yield_slice.store(to_nanos(slice), Ordering::Relaxed);
+  }
+
+  fn hooks(&self) -> Option<Arc<dyn SchedulerHooks>> {
+    self.hooks.read().unwrap().clone()
+  }
+
   pub(crate) fn has_pending_routines(&self, context_id: usize) -> bool {
     !self.contexts[context_id]
       .lock()
@@ -82,20 +143,74 @@ impl Scheduler {
       .is_empty()
   }
 
-  pub(crate) fn wait(&self, id: u64) {
+  pub(crate) fn wait(&self, id: u64) -> RoutineStatus {
     assert!(current_routine().id() != id);
-    let (wait_promise, wait_future) = Promise::<(), ()>::new_link();
+    let (wait_promise, wait_future) = Promise::<RoutineStatus, ()>::new_link();
     let has_wait = {
       let routine_ids = self.routine_ids.lock().unwrap();
       if let Some(routine) = routine_ids.get(&id).as_ref() {
-        unsafe { (***routine).wait(wait_promise) };
+        unsafe {
+          (***routine).wait(Box::new(move |status| {
+            wait_promise.resolve(status);
+          }))
+        };
         true
       } else {
         false
       }
     };
     if has_wait {
-      let _ = wait_future.result();
+      wait_future.result().unwrap()
+    } else {
+      RoutineStatus::NotFound
+    }
+  }
+
+  pub(crate) fn wait_any(&self, ids: &[u64]) -> (u64, RoutineStatus) {
+    assert!(!ids.is_empty());
+    let current_id = current_routine().id();
+    assert!(ids.iter().all(|id| *id != current_id));
+    let (wait_promise, wait_future) =
+      Promise::<(u64, RoutineStatus), ()>::new_link();
+    let wait_promise = Arc::new(Mutex::new(Some(wait_promise)));
+    {
+      let routine_ids = self.routine_ids.lock().unwrap();
+      if let Some(id) = ids.iter().find(|id| !routine_ids.contains_key(id)) {
+        return (*id, RoutineStatus::NotFound);
+      }
+      for id in ids {
+        let routine = routine_ids[id];
+        let id = *id;
+        let wait_promise = wait_promise.clone();
+        unsafe {
+          (*routine).wait(Box::new(move |status| {
+            if let Some(wait_promise) = wait_promise.lock().unwrap().take() {
+              wait_promise.resolve((id, status));
+            }
+          }))
+        };
+      }
+    }
+    wait_future.result().unwrap()
+  }
+
+  pub(crate) fn cancel(&self, id: u64) {
+    let cancel_handler = {
+      let routine_ids = self.routine_ids.lock().unwrap();
+      match routine_ids.get(&id) {
+        Some(routine) => unsafe { (**routine).cancel() },
+        None => None,
+      }
+    };
+    if let Some(cancel_handler) = cancel_handler {
+      cancel_handler();
+    }
+  }
+
+  pub(crate) fn set_priority(&self, id: u64, priority: RoutinePriority) {
+    let routine_ids = self.routine_ids.lock().unwrap();
+    if let Some(routine) = routine_ids.get(&id) {
+      unsafe { (**routine).set_priority(priority) };
     }
   }
 
@@ -104,16 +219,24 @@ impl Scheduler {
     f: F,
     stack_size: usize,
     context_id: usize,
+    priority: RoutinePriority,
   ) -> u64
   where
     F: 'static,
   {
-    let mut routine = ScheduledRoutine::new(f, stack_size, context_id);
+    self.submit(ScheduledRoutine::new(f, stack_size, context_id, priority))
+  }
+
+  pub(crate) fn submit(&self, mut routine: Box<dyn Routine>) -> u64 {
     let id = routine.id();
     {
       let mut routine_ids = self.routine_ids.lock().unwrap();
       routine_ids.insert(id, routine.as_mut() as *mut dyn Routine);
     }
+    self.spawn_count.fetch_add(1, Ordering::Relaxed);
+    if let Some(hooks) = self.hooks() {
+      hooks.on_spawn(id, routine.context_id());
+    }
     self.queue(Box::leak(routine));
     id
   }
@@ -141,7 +264,9 @@ impl Scheduler {
       context.pending_routines_available.notify_all();
       return;
     }
-    context.suspended_routines.insert(routine_ptr);
+    context
+      .suspended_routines
+      .insert(routine_ptr as *const (), routine_ptr);
   }
 
   pub(crate) fn resume(&self, routine: &mut dyn Routine) {
@@ -149,7 +274,10 @@ impl Scheduler {
       std::mem::transmute::<&mut dyn Routine, &'static mut dyn Routine>(routine)
     } as *mut dyn Routine;
     let context = &mut self.contexts[routine.context_id()].lock().unwrap();
-    if let Some(routine) = context.suspended_routines.take(&routine_ptr) {
+    if let Some(routine) = context
+      .suspended_routines
+      .remove(&(routine_ptr as *const ()))
+    {
       context.pending_routines.push_back(routine);
       context.pending_routines_available.notify_all();
     } else {
@@ -157,7 +285,8 @@ impl Scheduler {
     }
   }
 
-  fn run(&mut self, context: &mut Mutex<Context>) {
+  fn run(&self, context_id: usize) {
+    let context = &self.contexts[context_id];
     loop {
       let routine = {
         let mut context = context.lock().unwrap();
@@ -176,16 +305,42 @@ impl Scheduler {
         }
         unsafe { &mut *context.pending_routines.pop_front().unwrap() }
       };
+      let id = routine.id();
+      let hooks = self.hooks();
+      if let Some(hooks) = &hooks {
+        hooks.on_resume(id, context_id);
+      }
+      let start = Instant::now();
       routine.advance();
+      let elapsed = start.elapsed();
+      self.context_counters[context_id].record(elapsed);
+      let yield_slice = self.yield_slice();
+      if elapsed > yield_slice.saturating_mul(OVERRUN_FACTOR) {
+        #[cfg(feature = "tracing")]
+        trace_overrun(id, context_id, elapsed, yield_slice);
+        if let Some(hooks) = &hooks {
+          hooks.on_overrun(id, context_id, elapsed, yield_slice);
+        }
+      }
       match routine.state() {
         RoutineState::Complete => {
-          self.routine_ids.lock().unwrap().remove(&routine.id());
+          self.routine_ids.lock().unwrap().remove(&id);
           let _ = unsafe { Box::from_raw(routine as *mut dyn Routine) };
+          self.complete_count.fetch_add(1, Ordering::Relaxed);
+          if let Some(hooks) = &hooks {
+            hooks.on_complete(id, context_id, elapsed);
+          }
         }
         RoutineState::PendingSuspend => {
+          if let Some(hooks) = &hooks {
+            hooks.on_suspend(id, context_id, elapsed);
+          }
           self.suspend(routine);
         }
         _ => {
+          if let Some(hooks) = &hooks {
+            hooks.on_suspend(id, context_id, elapsed);
+          }
           self.queue(routine);
         }
       }
@@ -214,6 +369,26 @@ impl Drop for Scheduler {
   }
 }
 
+#[cfg(feature = "tracing")]
+fn trace_overrun(
+  id: u64,
+  context_id: usize,
+  elapsed: Duration,
+  yield_slice: Duration,
+) {
+  tracing::warn!(
+    routine = id,
+    context = context_id,
+    ?elapsed,
+    ?yield_slice,
+    "routine overran its yield slice"
+  );
+}
+
+fn to_nanos(duration: Duration) -> u64 {
+  u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
 static mut SCHEDULER: Option<Box<Scheduler>> = None;
 static SCHEDULER_INIT: Once = Once::new();
 
@@ -227,5 +402,28 @@ pub(crate) fn get_scheduler() -> &'static Scheduler {
 }
 
 pub fn spawn<F: FnOnce() + 'static>(f: F) -> u64 {
-  get_scheduler().spawn(f, 1024 * 1024, usize::MAX)
+  spawn_with_priority(f, RoutinePriority::Normal)
+}
+
+pub fn spawn_with_priority<F: FnOnce() + 'static>(
+  f: F,
+  priority: RoutinePriority,
+) -> u64 {
+  get_scheduler().spawn(f, 1024 * 1024, usize::MAX, priority)
+}
+
+pub fn scheduler_metrics() -> SchedulerMetrics {
+  get_scheduler().metrics()
+}
+
+pub fn set_scheduler_hooks(hooks: Option<Arc<dyn SchedulerHooks>>) {
+  get_scheduler().set_hooks(hooks);
+}
+
+pub fn yield_slice() -> Duration {
+  get_scheduler().yield_slice()
+}
+
+pub fn set_yield_slice(slice: Duration) {
+  get_scheduler().set_yield_slice(slice);
 }
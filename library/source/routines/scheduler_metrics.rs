@@ -0,0 +1,121 @@
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+pub trait SchedulerHooks: Send + Sync {
+  fn on_spawn(&self, _routine: u64, _context_id: usize) {}
+
+  fn on_resume(&self, _routine: u64, _context_id: usize) {}
+
+  fn on_suspend(&self, _routine: u64, _context_id: usize, _elapsed: Duration) {}
+
+  fn on_complete(&self, _routine: u64, _context_id: usize, _elapsed: Duration) {
+  }
+
+  fn on_overrun(
+    &self,
+    _routine: u64,
+    _context_id: usize,
+    _elapsed: Duration,
+    _yield_slice: Duration,
+  ) {
+  }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ContextMetrics {
+  pub pending_routines: usize,
+  pub suspended_routines: usize,
+  pub advance_count: u64,
+  pub advance_time: Duration,
+  pub max_advance_time: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub struct SchedulerMetrics {
+  pub timestamp: Instant,
+  pub spawn_count: u64,
+  pub complete_count: u64,
+  pub contexts: Vec<ContextMetrics>,
+}
+
+impl SchedulerMetrics {
+  pub fn pending_routines(&self) -> usize {
+    self
+      .contexts
+      .iter()
+      .map(|context| context.pending_routines)
+      .sum()
+  }
+
+  pub fn suspended_routines(&self) -> usize {
+    self
+      .contexts
+      .iter()
+      .map(|context| context.suspended_routines)
+      .sum()
+  }
+
+  pub fn advance_count(&self) -> u64 {
+    self
+      .contexts
+      .iter()
+      .map(|context| context.advance_count)
+      .sum()
+  }
+
+  pub fn context_switch_rate(&self, previous: &SchedulerMetrics) -> f64 {
+    self.rate(previous, self.advance_count(), previous.advance_count())
+  }
+
+  pub fn spawn_rate(&self, previous: &SchedulerMetrics) -> f64 {
+    self.rate(previous, self.spawn_count, previous.spawn_count)
+  }
+
+  pub fn complete_rate(&self, previous: &SchedulerMetrics) -> f64 {
+    self.rate(previous, self.complete_count, previous.complete_count)
+  }
+
+  fn rate(&self, previous: &SchedulerMetrics, current: u64, base: u64) -> f64 {
+    let elapsed = self.timestamp.duration_since(previous.timestamp);
+    if elapsed.is_zero() {
+      return 0.0;
+    }
+    current.saturating_sub(base) as f64 / elapsed.as_secs_f64()
+  }
+}
+
+#[derive(Default)]
+pub(crate) struct ContextCounters {
+  advance_count: AtomicU64,
+  advance_nanos: AtomicU64,
+  max_advance_nanos: AtomicU64,
+}
+
+impl ContextCounters {
+  pub(crate) fn record(&self, elapsed: Duration) {
+    let nanos = elapsed.as_nanos() as u64;
+    self.advance_count.fetch_add(1, Ordering::Relaxed);
+    self.advance_nanos.fetch_add(nanos, Ordering::Relaxed);
+    self.max_advance_nanos.fetch_max(nanos, Ordering::Relaxed);
+  }
+
+  pub(crate) fn load(
+    &self,
+    pending_routines: usize,
+    suspended_routines: usize,
+  ) -> ContextMetrics {
+    ContextMetrics {
+      pending_routines,
+      suspended_routines,
+      advance_count: self.advance_count.load(Ordering::Relaxed),
+      advance_time: Duration::from_nanos(
+        self.advance_nanos.load(Ordering::Relaxed),
+      ),
+      max_advance_time: Duration::from_nanos(
+        self.max_advance_nanos.load(Ordering::Relaxed),
+      ),
+    }
+  }
+}
@@ -0,0 +1,183 @@
+use std::collections::VecDeque;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::routines::external_routine::*;
+use crate::routines::routine::*;
+use crate::routines::scheduler::*;
+
+type Task = Box<dyn FnOnce() + Send>;
+
+struct TaskQueueData {
+  tasks: VecDeque<Task>,
+  is_running: bool,
+}
+
+#[derive(Clone)]
+pub struct TaskQueue {
+  data: Arc<Mutex<TaskQueueData>>,
+}
+
+impl TaskQueue {
+  pub fn new() -> Self {
+    TaskQueue {
+      data: Arc::new(Mutex::new(TaskQueueData {
+        tasks: VecDeque::new(),
+        is_running: false,
+      })),
+    }
+  }
+
+  pub fn push<F: FnOnce() + Send + 'static>(&self, f: F) {
+    let mut data = self.data.lock().unwrap();
+    data.tasks.push_back(Box::new(f));
+    if data.is_running {
+      return;
+    }
+    data.is_running = true;
+    drop(data);
+    get_scheduler().submit(Box::new(TaskQueueRoutine::new(self.data.clone())));
+  }
+
+  pub fn is_empty(&self) -> bool {
+    let data = self.data.lock().unwrap();
+    data.tasks.is_empty() && !data.is_running
+  }
+}
+
+impl Default for TaskQueue {
+  fn default() -> Self {
+    TaskQueue::new()
+  }
+}
+
+struct TaskQueueRoutine {
+  state: RoutineState,
+  id: u64,
+  context_id: usize,
+  advance_time: Instant,
+  data: Arc<Mutex<TaskQueueData>>,
+  blocking_routine: ExternalRoutine,
+  wait_callbacks: Mutex<Vec<WaitCallback>>,
+}
+
+impl TaskQueueRoutine {
+  fn new(data: Arc<Mutex<TaskQueueData>>) -> Self {
+    let id = ROUTINE_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+    TaskQueueRoutine {
+      state: RoutineState::Pending,
+      id,
+      context_id: id as usize % get_scheduler().thread_count(),
+      advance_time: Instant::now(),
+      data,
+      blocking_routine: ExternalRoutine::new(),
+      wait_callbacks: Mutex::new(Vec::new()),
+    }
+  }
+
+  fn next_task(&mut self) -> Option<Task> {
+    let mut data = self.data.lock().unwrap();
+    let task = data.tasks.pop_front();
+    if task.is_none() {
+      data.is_running = false;
+      self.state = RoutineState::Complete;
+    }
+    task
+  }
+}
+
+impl Routine for TaskQueueRoutine {
+  fn id(&self) -> u64 {
+    self.id
+  }
+
+  fn context_id(&self) -> usize {
+    self.context_id
+  }
+
+  fn state(&self) -> RoutineState {
+    self.state
+  }
+
+  fn is_pending_resume(&self) -> bool {
+    false
+  }
+
+  fn set_pending_resume(&mut self, _: bool) {}
+
+  fn is_cancelled(&self) -> bool {
+    false
+  }
+
+  fn cancel(&self) -> Option<CancelHandler> {
+    None
+  }
+
+  fn set_cancel_handler(&self, _: Option<CancelHandler>) -> bool {
+    true
+  }
+
+  fn priority(&self) -> RoutinePriority {
+    RoutinePriority::Normal
+  }
+
+  fn set_priority(&self, _: RoutinePriority) {}
+
+  fn slice_elapsed(&self) -> Duration {
+    self.advance_time.elapsed()
+  }
+
+  fn wait(&mut self, callback: WaitCallback) {
+    self.wait_callbacks.lock().unwrap().push(callback);
+  }
+
+  fn defer(&mut self) {}
+
+  fn pending_suspend(&mut self) {
+    self.blocking_routine.pending_suspend();
+  }
+
+  fn suspend(&mut self) {
+    self.blocking_routine.suspend();
+  }
+
+  fn resume(&mut self) {
+    self.blocking_routine.resume();
+  }
+
+  fn advance(&mut self) {
+    CURRENT_ROUTINE.with(|routine_cell| {
+      *routine_cell.borrow_mut() = Some(self as *mut dyn Routine);
+    });
+    self.state = RoutineState::Running;
+    self.advance_time = Instant::now();
+    let yield_slice = get_scheduler().yield_slice();
+    while let Some(task) = self.next_task() {
+      let _ = std::panic::catch_unwind(AssertUnwindSafe(task));
+      if self.slice_elapsed() >= yield_slice {
+        break;
+      }
+    }
+    CURRENT_ROUTINE.with(|routine_cell| {
+      *routine_cell.borrow_mut() = None;
+    });
+  }
+
+  fn set_state(&mut self, state: RoutineState) {
+    self.state = state;
+  }
+}
+
+impl Drop for TaskQueueRoutine {
+  fn drop(&mut self) {
+    let wait_callbacks =
+      std::mem::take(&mut *self.wait_callbacks.lock().unwrap());
+    for callback in wait_callbacks {
+      callback(RoutineStatus::Complete);
+    }
+  }
+}
@@ -0,0 +1,153 @@
+use crate::serialization::receiver::*;
+use crate::serialization::serialization_error::*;
+
+pub struct BinaryReceiver<'a> {
+  data: &'a [u8],
+  position: usize,
+  structures: Vec<usize>,
+}
+
+impl<'a> BinaryReceiver<'a> {
+  pub fn new(data: &'a [u8]) -> Self {
+    BinaryReceiver {
+      data,
+      position: 0,
+      structures: Vec::new(),
+    }
+  }
+
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  pub fn remaining(&self) -> usize {
+    self.limit() - self.position
+  }
+
+  fn limit(&self) -> usize {
+    self.structures.last().copied().unwrap_or(self.data.len())
+  }
+
+  fn take(&mut self, size: usize) -> Result<&'a [u8], SerializationError> {
+    if self.remaining() < size {
+      return Err(SerializationError::new("Unexpected end of data."));
+    }
+    let bytes = &self.data[self.position..self.position + size];
+    self.position += size;
+    Ok(bytes)
+  }
+
+  fn take_array<const N: usize>(
+    &mut self,
+  ) -> Result<[u8; N], SerializationError> {
+    Ok(self.take(N)?.try_into().unwrap())
+  }
+
+  fn receive_size(&mut self) -> Result<usize, SerializationError> {
+    Ok(u32::from_le_bytes(self.take_array()?) as usize)
+  }
+
+  fn receive_flag(&mut self) -> Result<bool, SerializationError> {
+    match self.take_array::<1>()?[0] {
+      0 => Ok(false),
+      1 => Ok(true),
+      _ => Err(SerializationError::new("Invalid boolean.")),
+    }
+  }
+}
+
+impl Receiver for BinaryReceiver<'_> {
+  fn receive_bool(&mut self, _: &str) -> Result<bool, SerializationError> {
+    self.receive_flag()
+  }
+
+  fn receive_i8(&mut self, _: &str) -> Result<i8, SerializationError> {
+    Ok(i8::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_i16(&mut self, _: &str) -> Result<i16, SerializationError> {
+    Ok(i16::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_i32(&mut self, _: &str) -> Result<i32, SerializationError> {
+    Ok(i32::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_i64(&mut self, _: &str) -> Result<i64, SerializationError> {
+    Ok(i64::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_u8(&mut self, _: &str) -> Result<u8, SerializationError> {
+    Ok(u8::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_u16(&mut self, _: &str) -> Result<u16, SerializationError> {
+    Ok(u16::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_u32(&mut self, _: &str) -> Result<u32, SerializationError> {
+    Ok(u32::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_u64(&mut self, _: &str) -> Result<u64, SerializationError> {
+    Ok(u64::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_f32(&mut self, _: &str) -> Result<f32, SerializationError> {
+    Ok(f32::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_f64(&mut self, _: &str) -> Result<f64, SerializationError> {
+    Ok(f64::from_le_bytes(self.take_array()?))
+  }
+
+  fn receive_string(&mut self, _: &str) -> Result<String, SerializationError> {
+    let size = self.receive_size()?;
+    let bytes = self.take(size)?;
+    String::from_utf8(bytes.to_vec())
+      .map_err(|_| SerializationError::new("Invalid UTF-8 string."))
+  }
+
+  fn start_structure(&mut self, _: &str) -> Result<u32, SerializationError> {
+    let mut version = 0u32;
+    for shift in (0..35).step_by(7) {
+      let byte = self.take_array::<1>()?[0];
+      version |= ((byte & 0x7F) as u32)
+        .checked_shl(shift)
+        .ok_or_else(|| SerializationError::new("Invalid version."))?;
+      if byte & 0x80 == 0 {
+        let size = self.receive_size()?;
+        if size > self.remaining() {
+          return Err(SerializationError::new("Unexpected end of data."));
+        }
+        self.structures.push(self.position + size);
+        return Ok(version);
+      }
+    }
+    Err(SerializationError::new("Invalid version."))
+  }
+
+  fn end_structure(&mut self) -> Result<(), SerializationError> {
+    let Some(end) = self.structures.pop() else {
+      return Err(SerializationError::new("Unbalanced structure."));
+    };
+    self.position = end;
+    Ok(())
+  }
+
+  fn start_sequence(&mut self, _: &str) -> Result<usize, SerializationError> {
+    self.receive_size()
+  }
+
+  fn end_sequence(&mut self) -> Result<(), SerializationError> {
+    Ok(())
+  }
+
+  fn start_optional(&mut self, _: &str) -> Result<bool, SerializationError> {
+    self.receive_flag()
+  }
+
+  fn end_optional(&mut self) -> Result<(), SerializationError> {
+    Ok(())
+  }
+}
@@ -0,0 +1,112 @@
+use crate::serialization::sender::*;
+
+#[derive(Clone, Debug, Default)]
+pub struct BinarySender {
+  data: Vec<u8>,
+  structures: Vec<usize>,
+}
+
+impl BinarySender {
+  pub fn new() -> Self {
+    BinarySender::default()
+  }
+
+  pub fn data(&self) -> &[u8] {
+    &self.data
+  }
+
+  pub fn into_data(self) -> Vec<u8> {
+    self.data
+  }
+
+  pub fn clear(&mut self) {
+    self.data.clear();
+    self.structures.clear();
+  }
+
+  fn send_size(&mut self, size: usize) {
+    self
+      .data
+      .extend_from_slice(&u32::try_from(size).unwrap().to_le_bytes());
+  }
+}
+
+impl Sender for BinarySender {
+  fn send_bool(&mut self, _: &str, value: bool) {
+    self.data.push(value as u8);
+  }
+
+  fn send_i8(&mut self, _: &str, value: i8) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_i16(&mut self, _: &str, value: i16) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_i32(&mut self, _: &str, value: i32) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_i64(&mut self, _: &str, value: i64) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_u8(&mut self, _: &str, value: u8) {
+    self.data.push(value);
+  }
+
+  fn send_u16(&mut self, _: &str, value: u16) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_u32(&mut self, _: &str, value: u32) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_u64(&mut self, _: &str, value: u64) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_f32(&mut self, _: &str, value: f32) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_f64(&mut self, _: &str, value: f64) {
+    self.data.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn send_str(&mut self, _: &str, value: &str) {
+    self.send_size(value.len());
+    self.data.extend_from_slice(value.as_bytes());
+  }
+
+  fn start_structure(&mut self, _: &str, version: u32) {
+    let mut version = version;
+    while version >= 0x80 {
+      self.data.push((version as u8) | 0x80);
+      version >>= 7;
+    }
+    self.data.push(version as u8);
+    self.structures.push(self.data.len());
+    self.data.extend_from_slice(&[0; 4]);
+  }
+
+  fn end_structure(&mut self) {
+    let position = self.structures.pop().unwrap();
+    let size = u32::try_from(self.data.len() - position - 4).unwrap();
+    self.data[position..position + 4].copy_from_slice(&size.to_le_bytes());
+  }
+
+  fn start_sequence(&mut self, _: &str, size: usize) {
+    self.send_size(size);
+  }
+
+  fn end_sequence(&mut self) {}
+
+  fn start_optional(&mut self, _: &str, is_present: bool) {
+    self.data.push(is_present as u8);
+  }
+
+  fn end_optional(&mut self) {}
+}
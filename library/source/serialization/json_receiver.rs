@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use crate::serialization::json_sender::*;
+use crate::serialization::json_value::*;
+use crate::serialization::receiver::*;
+use crate::serialization::serialization_error::*;
+
+enum Frame {
+  Structure(Vec<(String, JsonValue)>),
+  Sequence(VecDeque<JsonValue>),
+}
+
+pub struct JsonReceiver {
+  frames: Vec<Frame>,
+  values: VecDeque<JsonValue>,
+}
+
+impl JsonReceiver {
+  pub fn new(value: JsonValue) -> Self {
+    JsonReceiver {
+      frames: Vec::new(),
+      values: VecDeque::from([value]),
+    }
+  }
+
+  pub fn parse(text: &str) -> Result<Self, SerializationError> {
+    Ok(JsonReceiver::new(JsonValue::parse(text)?))
+  }
+
+  fn peek(&self, name: &str) -> Option<&JsonValue> {
+    match self.frames.last() {
+      None => self.values.front(),
+      Some(Frame::Structure(fields)) => fields
+        .iter()
+        .find(|(field, _)| field == name)
+        .map(|(_, value)| value),
+      Some(Frame::Sequence(values)) => values.front(),
+    }
+  }
+
+  fn next(&mut self, name: &str) -> Result<JsonValue, SerializationError> {
+    let value = match self.frames.last_mut() {
+      None => self.values.pop_front(),
+      Some(Frame::Structure(fields)) => fields
+        .iter()
+        .position(|(field, _)| field == name)
+        .map(|index| fields.remove(index).1),
+      Some(Frame::Sequence(values)) => values.pop_front(),
+    };
+    value.ok_or_else(|| {
+      SerializationError::new(format!("Missing value \"{}\".", name))
+    })
+  }
+
+  fn next_number<T: FromStr>(
+    &mut self,
+    name: &str,
+  ) -> Result<T, SerializationError> {
+    match self.next(name)? {
+      JsonValue::Number(text) => text.parse().map_err(|_| {
+        SerializationError::new(format!("Invalid number \"{}\".", name))
+      }),
+      _ => Err(SerializationError::new(format!(
+        "Expected number \"{}\".",
+        name
+      ))),
+    }
+  }
+
+  fn next_float(&mut self, name: &str) -> Result<f64, SerializationError> {
+    match self.peek(name) {
+      Some(JsonValue::Null) => {
+        self.next(name)?;
+        Ok(f64::NAN)
+      }
+      Some(JsonValue::String(text)) => {
+        let value = match text.as_str() {
+          NAN => f64::NAN,
+          INFINITY => f64::INFINITY,
+          NEGATIVE_INFINITY => f64::NEG_INFINITY,
+          _ => {
+            return Err(SerializationError::new(format!(
+              "Invalid number \"{}\".",
+              name
+            )))
+          }
+        };
+        self.next(name)?;
+        Ok(value)
+      }
+      _ => self.next_number(name),
+    }
+  }
+}
+
+impl Receiver for JsonReceiver {
+  fn receive_bool(&mut self, name: &str) -> Result<bool, SerializationError> {
+    match self.next(name)? {
+      JsonValue::Bool(value) => Ok(value),
+      _ => Err(SerializationError::new(format!(
+        "Expected boolean \"{}\".",
+        name
+      ))),
+    }
+  }
+
+  fn receive_i8(&mut self, name: &str) -> Result<i8, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_i16(&mut self, name: &str) -> Result<i16, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_i32(&mut self, name: &str) -> Result<i32, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_i64(&mut self, name: &str) -> Result<i64, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_u8(&mut self, name: &str) -> Result<u8, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_u16(&mut self, name: &str) -> Result<u16, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_u32(&mut self, name: &str) -> Result<u32, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_u64(&mut self, name: &str) -> Result<u64, SerializationError> {
+    self.next_number(name)
+  }
+
+  fn receive_f32(&mut self, name: &str) -> Result<f32, SerializationError> {
+    Ok(self.next_float(name)? as f32)
+  }
+
+  fn receive_f64(&mut self, name: &str) -> Result<f64, SerializationError> {
+    self.next_float(name)
+  }
+
+  fn receive_string(
+    &mut self,
+    name: &str,
+  ) -> Result<String, SerializationError> {
+    match self.next(name)? {
+      JsonValue::String(value) => Ok(value),
+      _ => Err(SerializationError::new(format!(
+        "Expected string \"{}\".",
+        name
+      ))),
+    }
+  }
+
+  fn start_structure(&mut self, name: &str) -> Result<u32, SerializationError> {
+    let JsonValue::Object(mut fields) = self.next(name)? else {
+      return Err(SerializationError::new(format!(
+        "Expected object \"{}\".",
+        name
+      )));
+    };
+    let version =
+      match fields.iter().position(|(field, _)| field == VERSION_FIELD) {
+        Some(index) => match fields.remove(index).1 {
+          JsonValue::Number(text) => text
+            .parse()
+            .map_err(|_| SerializationError::new("Invalid version."))?,
+          _ => return Err(SerializationError::new("Invalid version.")),
+        },
+        None => 0,
+      };
+    self.frames.push(Frame::Structure(fields));
+    Ok(version)
+  }
+
+  fn end_structure(&mut self) -> Result<(), SerializationError> {
+    match self.frames.pop() {
+      Some(Frame::Structure(_)) => Ok(()),
+      _ => Err(SerializationError::new("Unbalanced structure.")),
+    }
+  }
+
+  fn start_sequence(
+    &mut self,
+    name: &str,
+  ) -> Result<usize, SerializationError> {
+    let JsonValue::Array(values) = self.next(name)? else {
+      return Err(SerializationError::new(format!(
+        "Expected array \"{}\".",
+        name
+      )));
+    };
+    let size = values.len();
+    self.frames.push(Frame::Sequence(VecDeque::from(values)));
+    Ok(size)
+  }
+
+  fn end_sequence(&mut self) -> Result<(), SerializationError> {
+    match self.frames.pop() {
+      Some(Frame::Sequence(_)) => Ok(()),
+      _ => Err(SerializationError::new("Unbalanced sequence.")),
+    }
+  }
+
+  fn start_optional(&mut self, name: &str) -> Result<bool, SerializationError> {
+    match self.peek(name) {
+      None => {
+        if let Some(Frame::Structure(_)) = self.frames.last() {
+          Ok(false)
+        } else {
+          Err(SerializationError::new(format!(
+            "Missing value \"{}\".",
+            name
+          )))
+        }
+      }
+      Some(JsonValue::Null) => {
+        self.next(name)?;
+        Ok(false)
+      }
+      Some(_) => Ok(true),
+    }
+  }
+
+  fn end_optional(&mut self) -> Result<(), SerializationError> {
+    Ok(())
+  }
+}
@@ -0,0 +1,153 @@
+use crate::serialization::json_value::*;
+use crate::serialization::sender::*;
+
+pub(crate) const VERSION_FIELD: &str = "__version";
+pub(crate) const NAN: &str = "NaN";
+pub(crate) const INFINITY: &str = "Infinity";
+pub(crate) const NEGATIVE_INFINITY: &str = "-Infinity";
+
+enum Frame {
+  Structure(String, Vec<(String, JsonValue)>),
+  Sequence(String, Vec<JsonValue>),
+}
+
+#[derive(Default)]
+pub struct JsonSender {
+  frames: Vec<Frame>,
+  values: Vec<JsonValue>,
+}
+
+impl JsonSender {
+  pub fn new() -> Self {
+    JsonSender::default()
+  }
+
+  pub fn values(&self) -> &[JsonValue] {
+    &self.values
+  }
+
+  pub fn into_value(mut self) -> JsonValue {
+    match self.values.len() {
+      0 => JsonValue::Null,
+      1 => self.values.pop().unwrap(),
+      _ => JsonValue::Array(self.values),
+    }
+  }
+
+  pub fn into_string(self) -> String {
+    self.into_value().to_string()
+  }
+
+  fn emit(&mut self, name: &str, value: JsonValue) {
+    match self.frames.last_mut() {
+      None => self.values.push(value),
+      Some(Frame::Structure(_, fields)) => {
+        fields.push((name.to_string(), value))
+      }
+      Some(Frame::Sequence(_, values)) => values.push(value),
+    }
+  }
+
+  fn emit_number<T: ToString>(&mut self, name: &str, value: T) {
+    self.emit(name, JsonValue::Number(value.to_string()));
+  }
+
+  fn emit_float(&mut self, name: &str, value: f64) {
+    if value.is_finite() {
+      self.emit_number(name, value);
+    } else if value.is_nan() {
+      self.emit(name, JsonValue::String(NAN.to_string()));
+    } else if value.is_sign_positive() {
+      self.emit(name, JsonValue::String(INFINITY.to_string()));
+    } else {
+      self.emit(name, JsonValue::String(NEGATIVE_INFINITY.to_string()));
+    }
+  }
+}
+
+impl Sender for JsonSender {
+  fn send_bool(&mut self, name: &str, value: bool) {
+    self.emit(name, JsonValue::Bool(value));
+  }
+
+  fn send_i8(&mut self, name: &str, value: i8) {
+    self.emit_number(name, value);
+  }
+
+  fn send_i16(&mut self, name: &str, value: i16) {
+    self.emit_number(name, value);
+  }
+
+  fn send_i32(&mut self, name: &str, value: i32) {
+    self.emit_number(name, value);
+  }
+
+  fn send_i64(&mut self, name: &str, value: i64) {
+    self.emit_number(name, value);
+  }
+
+  fn send_u8(&mut self, name: &str, value: u8) {
+    self.emit_number(name, value);
+  }
+
+  fn send_u16(&mut self, name: &str, value: u16) {
+    self.emit_number(name, value);
+  }
+
+  fn send_u32(&mut self, name: &str, value: u32) {
+    self.emit_number(name, value);
+  }
+
+  fn send_u64(&mut self, name: &str, value: u64) {
+    self.emit_number(name, value);
+  }
+
+  fn send_f32(&mut self, name: &str, value: f32) {
+    self.emit_float(name, value as f64);
+  }
+
+  fn send_f64(&mut self, name: &str, value: f64) {
+    self.emit_float(name, value);
+  }
+
+  fn send_str(&mut self, name: &str, value: &str) {
+    self.emit(name, JsonValue::String(value.to_string()));
+  }
+
+  fn start_structure(&mut self, name: &str, version: u32) {
+    let mut fields = Vec::new();
+    if version != 0 {
+      fields.push((
+        VERSION_FIELD.to_string(),
+        JsonValue::Number(version.to_string()),
+      ));
+    }
+    self.frames.push(Frame::Structure(name.to_string(), fields));
+  }
+
+  fn end_structure(&mut self) {
+    if let Some(Frame::Structure(name, fields)) = self.frames.pop() {
+      self.emit(&name, JsonValue::Object(fields));
+    }
+  }
+
+  fn start_sequence(&mut self, name: &str, size: usize) {
+    self
+      .frames
+      .push(Frame::Sequence(name.to_string(), Vec::with_capacity(size)));
+  }
+
+  fn end_sequence(&mut self) {
+    if let Some(Frame::Sequence(name, values)) = self.frames.pop() {
+      self.emit(&name, JsonValue::Array(values));
+    }
+  }
+
+  fn start_optional(&mut self, name: &str, is_present: bool) {
+    if !is_present {
+      self.emit(name, JsonValue::Null);
+    }
+  }
+
+  fn end_optional(&mut self) {}
+}
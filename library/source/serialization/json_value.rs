@@ -0,0 +1,319 @@
+use std::fmt;
+
+use crate::serialization::serialization_error::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+  Null,
+  Bool(bool),
+  Number(String),
+  String(String),
+  Array(Vec<JsonValue>),
+  Object(Vec<(String, JsonValue)>),
+}
+
+pub const DEFAULT_MAX_JSON_DEPTH: usize = 128;
+
+impl JsonValue {
+  pub fn parse(text: &str) -> Result<Self, SerializationError> {
+    JsonValue::parse_with_max_depth(text, DEFAULT_MAX_JSON_DEPTH)
+  }
+
+  pub fn parse_with_max_depth(
+    text: &str,
+    max_depth: usize,
+  ) -> Result<Self, SerializationError> {
+    let mut parser = JsonParser {
+      text: text.as_bytes(),
+      position: 0,
+      depth: 0,
+      max_depth,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if parser.position != parser.text.len() {
+      return Err(parser.error("Unexpected trailing characters."));
+    }
+    Ok(value)
+  }
+}
+
+impl fmt::Display for JsonValue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      JsonValue::Null => f.write_str("null"),
+      JsonValue::Bool(value) => write!(f, "{}", value),
+      JsonValue::Number(value) => f.write_str(value),
+      JsonValue::String(value) => write_string(f, value),
+      JsonValue::Array(values) => {
+        f.write_str("[")?;
+        for (index, value) in values.iter().enumerate() {
+          if index != 0 {
+            f.write_str(",")?;
+          }
+          write!(f, "{}", value)?;
+        }
+        f.write_str("]")
+      }
+      JsonValue::Object(fields) => {
+        f.write_str("{")?;
+        for (index, (name, value)) in fields.iter().enumerate() {
+          if index != 0 {
+            f.write_str(",")?;
+          }
+          write_string(f, name)?;
+          write!(f, ":{}", value)?;
+        }
+        f.write_str("}")
+      }
+    }
+  }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+  f.write_str("\"")?;
+  for character in value.chars() {
+    match character {
+      '"' => f.write_str("\\\"")?,
+      '\\' => f.write_str("\\\\")?,
+      '\n' => f.write_str("\\n")?,
+      '\r' => f.write_str("\\r")?,
+      '\t' => f.write_str("\\t")?,
+      '\u{08}' => f.write_str("\\b")?,
+      '\u{0C}' => f.write_str("\\f")?,
+      character if (character as u32) < 0x20 => {
+        write!(f, "\\u{:04x}", character as u32)?
+      }
+      character => write!(f, "{}", character)?,
+    }
+  }
+  f.write_str("\"")
+}
+
+struct JsonParser<'a> {
+  text: &'a [u8],
+  position: usize,
+  depth: usize,
+  max_depth: usize,
+}
+
+impl JsonParser<'_> {
+  fn error(&self, message: &str) -> SerializationError {
+    SerializationError::new(format!("{} At offset {}.", message, self.position))
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.position < self.text.len()
+      && matches!(self.text[self.position], b' ' | b'\t' | b'\n' | b'\r')
+    {
+      self.position += 1;
+    }
+  }
+
+  fn peek(&self) -> Option<u8> {
+    self.text.get(self.position).copied()
+  }
+
+  fn expect(&mut self, byte: u8) -> Result<(), SerializationError> {
+    self.skip_whitespace();
+    if self.peek() != Some(byte) {
+      return Err(self.error(&format!("Expected '{}'.", byte as char)));
+    }
+    self.position += 1;
+    Ok(())
+  }
+
+  fn parse_literal(
+    &mut self,
+    literal: &str,
+    value: JsonValue,
+  ) -> Result<JsonValue, SerializationError> {
+    if !self.text[self.position..].starts_with(literal.as_bytes()) {
+      return Err(self.error("Invalid literal."));
+    }
+    self.position += literal.len();
+    Ok(value)
+  }
+
+  fn parse_value(&mut self) -> Result<JsonValue, SerializationError> {
+    self.skip_whitespace();
+    match self.peek() {
+      None => Err(self.error("Unexpected end of input.")),
+      Some(b'n') => self.parse_literal("null", JsonValue::Null),
+      Some(b't') => self.parse_literal("true", JsonValue::Bool(true)),
+      Some(b'f') => self.parse_literal("false", JsonValue::Bool(false)),
+      Some(b'"') => Ok(JsonValue::String(self.parse_string()?)),
+      Some(b'[') => self.parse_nested(Self::parse_array),
+      Some(b'{') => self.parse_nested(Self::parse_object),
+      Some(b'-' | b'0'..=b'9') => self.parse_number(),
+      Some(_) => Err(self.error("Unexpected character.")),
+    }
+  }
+
+  fn parse_nested(
+    &mut self,
+    parse: fn(&mut Self) -> Result<JsonValue, SerializationError>,
+  ) -> Result<JsonValue, SerializationError> {
+    if self.depth == self.max_depth {
+      return Err(self.error("Maximum nesting depth exceeded."));
+    }
+    self.depth += 1;
+    let value = parse(self);
+    self.depth -= 1;
+    value
+  }
+
+  fn parse_number(&mut self) -> Result<JsonValue, SerializationError> {
+    let start = self.position;
+    if self.peek() == Some(b'-') {
+      self.position += 1;
+    }
+    match self.peek() {
+      Some(b'0') => self.position += 1,
+      Some(b'1'..=b'9') => self.skip_digits(),
+      _ => return Err(self.error("Invalid number.")),
+    }
+    if self.peek() == Some(b'.') {
+      self.position += 1;
+      self.expect_digits()?;
+    }
+    if let Some(b'e' | b'E') = self.peek() {
+      self.position += 1;
+      if let Some(b'+' | b'-') = self.peek() {
+        self.position += 1;
+      }
+      self.expect_digits()?;
+    }
+    let text = std::str::from_utf8(&self.text[start..self.position]).unwrap();
+    Ok(JsonValue::Number(text.to_string()))
+  }
+
+  fn skip_digits(&mut self) {
+    while let Some(b'0'..=b'9') = self.peek() {
+      self.position += 1;
+    }
+  }
+
+  fn expect_digits(&mut self) -> Result<(), SerializationError> {
+    if !matches!(self.peek(), Some(b'0'..=b'9')) {
+      return Err(self.error("Invalid number."));
+    }
+    self.skip_digits();
+    Ok(())
+  }
+
+  fn parse_hex(&mut self) -> Result<u32, SerializationError> {
+    if self.position + 4 > self.text.len() {
+      return Err(self.error("Invalid escape sequence."));
+    }
+    let digits = std::str::from_utf8(&self.text[self.position..][..4])
+      .map_err(|_| self.error("Invalid escape sequence."))?;
+    let value = u32::from_str_radix(digits, 16)
+      .map_err(|_| self.error("Invalid escape sequence."))?;
+    self.position += 4;
+    Ok(value)
+  }
+
+  fn parse_string(&mut self) -> Result<String, SerializationError> {
+    self.expect(b'"')?;
+    let mut bytes = Vec::new();
+    loop {
+      let Some(byte) = self.peek() else {
+        return Err(self.error("Unterminated string."));
+      };
+      self.position += 1;
+      match byte {
+        b'"' => break,
+        b'\\' => {
+          let Some(escape) = self.peek() else {
+            return Err(self.error("Unterminated string."));
+          };
+          self.position += 1;
+          let character = match escape {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{08}',
+            b'f' => '\u{0C}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+              let mut code = self.parse_hex()?;
+              if (0xD800..0xDC00).contains(&code) {
+                if !self.text[self.position..].starts_with(b"\\u") {
+                  return Err(self.error("Invalid surrogate pair."));
+                }
+                self.position += 2;
+                let low = self.parse_hex()?;
+                if !(0xDC00..0xE000).contains(&low) {
+                  return Err(self.error("Invalid surrogate pair."));
+                }
+                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+              }
+              char::from_u32(code)
+                .ok_or_else(|| self.error("Invalid escape sequence."))?
+            }
+            _ => return Err(self.error("Invalid escape sequence.")),
+          };
+          let mut buffer = [0u8; 4];
+          bytes
+            .extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+        }
+        0x00..=0x1F => {
+          self.position -= 1;
+          return Err(self.error("Unescaped control character in string."));
+        }
+        byte => bytes.push(byte),
+      }
+    }
+    String::from_utf8(bytes).map_err(|_| self.error("Invalid UTF-8 string."))
+  }
+
+  fn parse_array(&mut self) -> Result<JsonValue, SerializationError> {
+    self.expect(b'[')?;
+    let mut values = Vec::new();
+    self.skip_whitespace();
+    if self.peek() == Some(b']') {
+      self.position += 1;
+      return Ok(JsonValue::Array(values));
+    }
+    loop {
+      values.push(self.parse_value()?);
+      self.skip_whitespace();
+      match self.peek() {
+        Some(b',') => self.position += 1,
+        Some(b']') => {
+          self.position += 1;
+          return Ok(JsonValue::Array(values));
+        }
+        _ => return Err(self.error("Expected ',' or ']'.")),
+      }
+    }
+  }
+
+  fn parse_object(&mut self) -> Result<JsonValue, SerializationError> {
+    self.expect(b'{')?;
+    let mut fields = Vec::new();
+    self.skip_whitespace();
+    if self.peek() == Some(b'}') {
+      self.position += 1;
+      return Ok(JsonValue::Object(fields));
+    }
+    loop {
+      self.skip_whitespace();
+      let name = self.parse_string()?;
+      self.expect(b':')?;
+      fields.push((name, self.parse_value()?));
+      self.skip_whitespace();
+      match self.peek() {
+        Some(b',') => self.position += 1,
+        Some(b'}') => {
+          self.position += 1;
+          return Ok(JsonValue::Object(fields));
+        }
+        _ => return Err(self.error("Expected ',' or '}'.")),
+      }
+    }
+  }
+}
@@ -0,0 +1,24 @@
+mod binary_receiver;
+mod binary_sender;
+mod json_receiver;
+mod json_sender;
+mod json_value;
+mod receiver;
+mod round_trip;
+mod sender;
+mod serialization_error;
+mod shuttle;
+mod type_registry;
+
+pub use beam_derive::Shuttle;
+pub use binary_receiver::*;
+pub use binary_sender::*;
+pub use json_receiver::*;
+pub use json_sender::*;
+pub use json_value::*;
+pub use receiver::*;
+pub use round_trip::*;
+pub use sender::*;
+pub use serialization_error::*;
+pub use shuttle::*;
+pub use type_registry::*;
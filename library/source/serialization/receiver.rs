@@ -0,0 +1,43 @@
+use crate::serialization::serialization_error::*;
+
+pub trait Receiver {
+  fn receive_bool(&mut self, name: &str) -> Result<bool, SerializationError>;
+
+  fn receive_i8(&mut self, name: &str) -> Result<i8, SerializationError>;
+
+  fn receive_i16(&mut self, name: &str) -> Result<i16, SerializationError>;
+
+  fn receive_i32(&mut self, name: &str) -> Result<i32, SerializationError>;
+
+  fn receive_i64(&mut self, name: &str) -> Result<i64, SerializationError>;
+
+  fn receive_u8(&mut self, name: &str) -> Result<u8, SerializationError>;
+
+  fn receive_u16(&mut self, name: &str) -> Result<u16, SerializationError>;
+
+  fn receive_u32(&mut self, name: &str) -> Result<u32, SerializationError>;
+
+  fn receive_u64(&mut self, name: &str) -> Result<u64, SerializationError>;
+
+  fn receive_f32(&mut self, name: &str) -> Result<f32, SerializationError>;
+
+  fn receive_f64(&mut self, name: &str) -> Result<f64, SerializationError>;
+
+  fn receive_string(
+    &mut self,
+    name: &str,
+  ) -> Result<String, SerializationError>;
+
+  fn start_structure(&mut self, name: &str) -> Result<u32, SerializationError>;
+
+  fn end_structure(&mut self) -> Result<(), SerializationError>;
+
+  fn start_sequence(&mut self, name: &str)
+    -> Result<usize, SerializationError>;
+
+  fn end_sequence(&mut self) -> Result<(), SerializationError>;
+
+  fn start_optional(&mut self, name: &str) -> Result<bool, SerializationError>;
+
+  fn end_optional(&mut self) -> Result<(), SerializationError>;
+}
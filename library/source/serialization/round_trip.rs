@@ -0,0 +1,28 @@
+use crate::serialization::binary_receiver::*;
+use crate::serialization::binary_sender::*;
+use crate::serialization::json_receiver::*;
+use crate::serialization::json_sender::*;
+use crate::serialization::serialization_error::*;
+use crate::serialization::shuttle::*;
+
+pub fn round_trip<T: Shuttle, U: Shuttle>(
+  value: &T,
+) -> Result<U, SerializationError> {
+  let mut sender = BinarySender::new();
+  value.send(&mut sender, "value");
+  let mut receiver = BinaryReceiver::new(sender.data());
+  let result = U::receive(&mut receiver, "value")?;
+  if receiver.remaining() != 0 {
+    return Err(SerializationError::new("Trailing data."));
+  }
+  Ok(result)
+}
+
+pub fn round_trip_json<T: Shuttle, U: Shuttle>(
+  value: &T,
+) -> Result<U, SerializationError> {
+  let mut sender = JsonSender::new();
+  value.send(&mut sender, "value");
+  let mut receiver = JsonReceiver::new(sender.into_value());
+  U::receive(&mut receiver, "value")
+}
@@ -0,0 +1,37 @@
+pub trait Sender {
+  fn send_bool(&mut self, name: &str, value: bool);
+
+  fn send_i8(&mut self, name: &str, value: i8);
+
+  fn send_i16(&mut self, name: &str, value: i16);
+
+  fn send_i32(&mut self, name: &str, value: i32);
+
+  fn send_i64(&mut self, name: &str, value: i64);
+
+  fn send_u8(&mut self, name: &str, value: u8);
+
+  fn send_u16(&mut self, name: &str, value: u16);
+
+  fn send_u32(&mut self, name: &str, value: u32);
+
+  fn send_u64(&mut self, name: &str, value: u64);
+
+  fn send_f32(&mut self, name: &str, value: f32);
+
+  fn send_f64(&mut self, name: &str, value: f64);
+
+  fn send_str(&mut self, name: &str, value: &str);
+
+  fn start_structure(&mut self, name: &str, version: u32);
+
+  fn end_structure(&mut self);
+
+  fn start_sequence(&mut self, name: &str, size: usize);
+
+  fn end_sequence(&mut self);
+
+  fn start_optional(&mut self, name: &str, is_present: bool);
+
+  fn end_optional(&mut self);
+}
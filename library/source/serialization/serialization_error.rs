@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SerializationError {
+  message: String,
+}
+
+impl SerializationError {
+  pub fn new(message: impl Into<String>) -> Self {
+    SerializationError {
+      message: message.into(),
+    }
+  }
+
+  pub fn message(&self) -> &str {
+    &self.message
+  }
+}
+
+impl fmt::Display for SerializationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.message)
+  }
+}
+
+impl std::error::Error for SerializationError {}
@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::serialization::receiver::*;
+use crate::serialization::sender::*;
+use crate::serialization::serialization_error::*;
+
+pub trait Shuttle: Sized {
+  const VERSION: u32 = 0;
+
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str);
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError>;
+}
+
+macro_rules! impl_primitive_shuttle {
+  ($($type:ty => $send:ident, $receive:ident;)*) => {
+    $(
+      impl Shuttle for $type {
+        fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+          sender.$send(name, *self);
+        }
+
+        fn receive<R: Receiver + ?Sized>(
+          receiver: &mut R,
+          name: &str,
+        ) -> Result<Self, SerializationError> {
+          receiver.$receive(name)
+        }
+      }
+    )*
+  };
+}
+
+impl_primitive_shuttle! {
+  bool => send_bool, receive_bool;
+  i8 => send_i8, receive_i8;
+  i16 => send_i16, receive_i16;
+  i32 => send_i32, receive_i32;
+  i64 => send_i64, receive_i64;
+  u8 => send_u8, receive_u8;
+  u16 => send_u16, receive_u16;
+  u32 => send_u32, receive_u32;
+  u64 => send_u64, receive_u64;
+  f32 => send_f32, receive_f32;
+  f64 => send_f64, receive_f64;
+}
+
+impl Shuttle for String {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    sender.send_str(name, self);
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    receiver.receive_string(name)
+  }
+}
+
+impl<T: Shuttle> Shuttle for Box<T> {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    self.as_ref().send(sender, name);
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    Ok(Box::new(T::receive(receiver, name)?))
+  }
+}
+
+impl<T: Shuttle> Shuttle for Option<T> {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    sender.start_optional(name, self.is_some());
+    if let Some(value) = self {
+      value.send(sender, name);
+    }
+    sender.end_optional();
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    let value = if receiver.start_optional(name)? {
+      Some(T::receive(receiver, name)?)
+    } else {
+      None
+    };
+    receiver.end_optional()?;
+    Ok(value)
+  }
+}
+
+impl<T: Shuttle> Shuttle for Vec<T> {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    sender.start_sequence(name, self.len());
+    for value in self {
+      value.send(sender, "");
+    }
+    sender.end_sequence();
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    let size = receiver.start_sequence(name)?;
+    let mut values = Vec::with_capacity(size.min(1024));
+    for _ in 0..size {
+      values.push(T::receive(receiver, "")?);
+    }
+    receiver.end_sequence()?;
+    Ok(values)
+  }
+}
+
+impl<K: Shuttle + Eq + Hash, V: Shuttle> Shuttle for HashMap<K, V> {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    sender.start_sequence(name, self.len());
+    for (key, value) in self {
+      sender.start_sequence("", 2);
+      key.send(sender, "");
+      value.send(sender, "");
+      sender.end_sequence();
+    }
+    sender.end_sequence();
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    let size = receiver.start_sequence(name)?;
+    let mut values = HashMap::with_capacity(size.min(1024));
+    for _ in 0..size {
+      if receiver.start_sequence("")? != 2 {
+        return Err(SerializationError::new("Invalid map entry."));
+      }
+      let key = K::receive(receiver, "")?;
+      let value = V::receive(receiver, "")?;
+      receiver.end_sequence()?;
+      values.insert(key, value);
+    }
+    receiver.end_sequence()?;
+    Ok(values)
+  }
+}
+
+macro_rules! impl_tuple_shuttle {
+  ($($size:literal => ($($type:ident $index:tt),*);)*) => {
+    $(
+      impl<$($type: Shuttle),*> Shuttle for ($($type,)*) {
+        fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+          sender.start_sequence(name, $size);
+          $(self.$index.send(sender, "");)*
+          sender.end_sequence();
+        }
+
+        fn receive<R: Receiver + ?Sized>(
+          receiver: &mut R,
+          name: &str,
+        ) -> Result<Self, SerializationError> {
+          if receiver.start_sequence(name)? != $size {
+            return Err(SerializationError::new("Invalid tuple size."));
+          }
+          let value = ($($type::receive(receiver, "")?,)*);
+          receiver.end_sequence()?;
+          Ok(value)
+        }
+      }
+    )*
+  };
+}
+
+impl_tuple_shuttle! {
+  1 => (A 0);
+  2 => (A 0, B 1);
+  3 => (A 0, B 1, C 2);
+  4 => (A 0, B 1, C 2, D 3);
+  5 => (A 0, B 1, C 2, D 3, E 4);
+  6 => (A 0, B 1, C 2, D 3, E 4, F 5);
+}
+
+impl Shuttle for Duration {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    sender.start_structure(name, Self::VERSION);
+    sender.send_u64("seconds", self.as_secs());
+    sender.send_u32("nanoseconds", self.subsec_nanos());
+    sender.end_structure();
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    receiver.start_structure(name)?;
+    let seconds = receiver.receive_u64("seconds")?;
+    let nanoseconds = receiver.receive_u32("nanoseconds")?;
+    receiver.end_structure()?;
+    if nanoseconds >= 1_000_000_000 {
+      return Err(SerializationError::new("Invalid duration."));
+    }
+    Ok(Duration::new(seconds, nanoseconds))
+  }
+}
+
+impl Shuttle for SystemTime {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    let (seconds, nanoseconds) =
+      match self.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(error) => {
+          let duration = error.duration();
+          if duration.subsec_nanos() == 0 {
+            (-(duration.as_secs() as i64), 0)
+          } else {
+            (
+              -(duration.as_secs() as i64) - 1,
+              1_000_000_000 - duration.subsec_nanos(),
+            )
+          }
+        }
+      };
+    sender.start_structure(name, Self::VERSION);
+    sender.send_i64("seconds", seconds);
+    sender.send_u32("nanoseconds", nanoseconds);
+    sender.end_structure();
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    receiver.start_structure(name)?;
+    let seconds = receiver.receive_i64("seconds")?;
+    let nanoseconds = receiver.receive_u32("nanoseconds")?;
+    receiver.end_structure()?;
+    if nanoseconds >= 1_000_000_000 {
+      return Err(SerializationError::new("Invalid time."));
+    }
+    let time = if seconds >= 0 {
+      SystemTime::UNIX_EPOCH
+        .checked_add(Duration::new(seconds as u64, nanoseconds))
+    } else {
+      SystemTime::UNIX_EPOCH
+        .checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+        .and_then(|time| time.checked_add(Duration::new(0, nanoseconds)))
+    };
+    time.ok_or_else(|| SerializationError::new("Invalid time."))
+  }
+}
@@ -0,0 +1,185 @@
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::serialization::receiver::*;
+use crate::serialization::sender::*;
+use crate::serialization::serialization_error::*;
+use crate::serialization::shuttle::*;
+
+pub trait Polymorphic: Any {
+  fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> Polymorphic for T {
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+}
+
+type SendFn = fn(&dyn Any, &mut dyn Sender, &str);
+
+type ReceiveFn<B> = Box<
+  dyn Fn(&mut dyn Receiver, &str) -> Result<Box<B>, SerializationError>
+    + Send
+    + Sync,
+>;
+
+struct TypeEntry<B: ?Sized> {
+  name: String,
+  version: u32,
+  send: SendFn,
+  receive: ReceiveFn<B>,
+}
+
+pub struct TypeRegistry<B: ?Sized + Polymorphic> {
+  entries: Vec<TypeEntry<B>>,
+  names: HashMap<String, usize>,
+  types: HashMap<TypeId, usize>,
+}
+
+impl<B: ?Sized + Polymorphic> TypeRegistry<B> {
+  pub fn new() -> Self {
+    TypeRegistry {
+      entries: Vec::new(),
+      names: HashMap::new(),
+      types: HashMap::new(),
+    }
+  }
+
+  pub fn register<T: Shuttle + Any>(
+    &mut self,
+    name: &str,
+    upcast: fn(T) -> Box<B>,
+  ) {
+    assert!(
+      !self.names.contains_key(name),
+      "Type name \"{}\" is already registered.",
+      name
+    );
+    assert!(
+      !self.types.contains_key(&TypeId::of::<T>()),
+      "Type {} is already registered.",
+      std::any::type_name::<T>()
+    );
+    let index = self.entries.len();
+    self.entries.push(TypeEntry {
+      name: name.to_string(),
+      version: T::VERSION,
+      send: send_entry::<T>,
+      receive: Box::new(move |receiver, name| {
+        Ok(upcast(T::receive(receiver, name)?))
+      }),
+    });
+    self.names.insert(name.to_string(), index);
+    self.types.insert(TypeId::of::<T>(), index);
+  }
+
+  pub fn contains(&self, name: &str) -> bool {
+    self.names.contains_key(name)
+  }
+
+  pub fn name_of(&self, value: &B) -> Option<&str> {
+    self
+      .types
+      .get(&value.as_any().type_id())
+      .map(|index| self.entries[*index].name.as_str())
+  }
+
+  pub fn send<S: Sender>(
+    &self,
+    sender: &mut S,
+    name: &str,
+    value: &B,
+  ) -> Result<(), SerializationError> {
+    let any = value.as_any();
+    let Some(index) = self.types.get(&any.type_id()) else {
+      return Err(SerializationError::new("Type is not registered."));
+    };
+    let entry = &self.entries[*index];
+    sender.start_structure(name, 0);
+    sender.send_str("type", &entry.name);
+    (entry.send)(any, sender, "value");
+    sender.end_structure();
+    Ok(())
+  }
+
+  pub fn receive<R: Receiver>(
+    &self,
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Box<B>, SerializationError> {
+    receiver.start_structure(name)?;
+    let type_name = receiver.receive_string("type")?;
+    let Some(index) = self.names.get(&type_name) else {
+      return Err(SerializationError::new(format!(
+        "Type \"{}\" is not registered.",
+        type_name
+      )));
+    };
+    let value = (self.entries[*index].receive)(receiver, "value")?;
+    receiver.end_structure()?;
+    Ok(value)
+  }
+
+  pub fn manifest(&self) -> Vec<(String, u32)> {
+    let mut manifest = self
+      .entries
+      .iter()
+      .map(|entry| (entry.name.clone(), entry.version))
+      .collect::<Vec<_>>();
+    manifest.sort();
+    manifest
+  }
+
+  pub fn fingerprint(&self) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64;
+    for (name, _) in self.manifest() {
+      for byte in name.bytes().chain([0]) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+      }
+    }
+    hash
+  }
+
+  pub fn verify(
+    &self,
+    manifest: &[(String, u32)],
+  ) -> Result<(), SerializationError> {
+    if let Some((name, _)) = manifest
+      .iter()
+      .find(|(name, _)| !self.names.contains_key(name))
+    {
+      return Err(SerializationError::new(format!(
+        "Type \"{}\" is not registered locally.",
+        name
+      )));
+    }
+    if let Some(entry) = self
+      .entries
+      .iter()
+      .find(|entry| !manifest.iter().any(|(name, _)| *name == entry.name))
+    {
+      return Err(SerializationError::new(format!(
+        "Type \"{}\" is not registered remotely.",
+        entry.name
+      )));
+    }
+    Ok(())
+  }
+}
+
+impl<B: ?Sized + Polymorphic> Default for TypeRegistry<B> {
+  fn default() -> Self {
+    TypeRegistry::new()
+  }
+}
+
+fn send_entry<T: Shuttle + Any>(
+  value: &dyn Any,
+  sender: &mut dyn Sender,
+  name: &str,
+) {
+  value.downcast_ref::<T>().unwrap().send(sender, name);
+}
@@ -1,7 +1,9 @@
 use std::cmp::*;
 use std::u32;
 
-#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+use crate::serialization::*;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Shuttle)]
 pub enum DirectoryCategory {
   None,
   Account,
@@ -14,7 +16,7 @@ impl Default for DirectoryCategory {
   }
 }
 
-#[derive(Clone, Debug, Eq, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialOrd, Shuttle)]
 pub struct DirectoryEntry {
   pub category: DirectoryCategory,
   pub id: u32,
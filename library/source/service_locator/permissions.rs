@@ -1,5 +1,7 @@
 use bitflags::*;
 
+use crate::serialization::*;
+
 bitflags! {
   #[derive(Debug)]
   pub struct Permissions: u8 {
@@ -8,3 +10,16 @@ bitflags! {
     const ADMINISTER = 0b0100;
   }
 }
+
+impl Shuttle for Permissions {
+  fn send<S: Sender + ?Sized>(&self, sender: &mut S, name: &str) {
+    sender.send_u8(name, self.bits());
+  }
+
+  fn receive<R: Receiver + ?Sized>(
+    receiver: &mut R,
+    name: &str,
+  ) -> Result<Self, SerializationError> {
+    Ok(Permissions::from_bits_truncate(receiver.receive_u8(name)?))
+  }
+}
@@ -0,0 +1,5 @@
+mod message_protocol;
+mod service_protocol_client;
+
+pub use message_protocol::*;
+pub use service_protocol_client::*;
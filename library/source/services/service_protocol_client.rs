@@ -1,7 +1,23 @@
-pub struct ServiceProtocolClient<S> {
-  session: S,
+use crate::io::*;
+
+pub struct ServiceProtocolClient<C: Channel> {
+  channel: C,
 }
 
-impl ServiceProtocolClient {
+impl<C: Channel> ServiceProtocolClient<C> {
+  pub fn new(channel: C) -> Self {
+    ServiceProtocolClient { channel }
+  }
+
+  pub fn channel(&self) -> &C {
+    &self.channel
+  }
+
+  pub fn identifier(&self) -> &C::Identifier {
+    self.channel.identifier()
+  }
 
+  pub fn close(&self) -> std::io::Result<()> {
+    self.channel.connection().close()
+  }
 }
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::threading::timer::*;
+use crate::threading::timer_queue::*;
+
+#[derive(Clone)]
+pub struct LiveTimer {
+  interval: Duration,
+  core: Arc<TimerCore>,
+  entry: Arc<Mutex<Option<TimerKey>>>,
+}
+
+impl LiveTimer {
+  pub fn new(interval: Duration) -> Self {
+    LiveTimer {
+      interval,
+      core: Arc::new(TimerCore::new()),
+      entry: Arc::new(Mutex::new(None)),
+    }
+  }
+
+  pub fn interval(&self) -> Duration {
+    self.interval
+  }
+}
+
+impl Timer for LiveTimer {
+  fn start(&self) {
+    let mut entry = self.entry.lock().unwrap();
+    let Some(generation) = self.core.start() else {
+      return;
+    };
+    let core = self.core.clone();
+    *entry = Some(get_timer_queue().schedule(
+      Instant::now() + self.interval,
+      Box::new(move || core.expire(generation)),
+    ));
+  }
+
+  fn cancel(&self) {
+    let entry = self.entry.lock().unwrap().take();
+    self.core.cancel();
+    if let Some(key) = entry {
+      get_timer_queue().cancel(key);
+    }
+  }
+
+  fn wait(&self) -> TimerResult {
+    self.core.wait()
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LiveTimerFactory;
+
+impl TimerFactory for LiveTimerFactory {
+  type Timer = LiveTimer;
+
+  fn create(&self, interval: Duration) -> LiveTimer {
+    LiveTimer::new(interval)
+  }
+}
+
+pub fn sleep(duration: Duration) {
+  let timer = LiveTimer::new(duration);
+  timer.start();
+  timer.wait();
+}
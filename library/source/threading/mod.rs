@@ -0,0 +1,12 @@
+mod live_timer;
+mod mutex;
+mod sync;
+mod timer;
+mod timer_queue;
+mod trigger_timer;
+
+pub use live_timer::*;
+pub use mutex::*;
+pub use sync::*;
+pub use timer::*;
+pub use trigger_timer::*;
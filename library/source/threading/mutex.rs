@@ -0,0 +1,107 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::routines::suspended_routine_queue::*;
+
+struct MutexState {
+  is_locked: bool,
+  suspended_routines: SuspendedRoutineQueue,
+}
+
+pub struct Mutex<T> {
+  state: std::sync::Mutex<MutexState>,
+  value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Send for Mutex<T> {}
+unsafe impl<T: Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+  pub fn new(value: T) -> Self {
+    Mutex {
+      state: std::sync::Mutex::new(MutexState {
+        is_locked: false,
+        suspended_routines: SuspendedRoutineQueue::new(
+          SuspendedRoutineNodeAdapter::new(),
+        ),
+      }),
+      value: UnsafeCell::new(value),
+    }
+  }
+
+  pub fn lock(&self) -> MutexGuard<'_, T> {
+    let mut state = self.state.lock().unwrap();
+    while state.is_locked {
+      let suspended_routines = &mut state.suspended_routines as *mut _;
+      suspend(unsafe { &mut *suspended_routines }, state);
+      state = self.state.lock().unwrap();
+    }
+    state.is_locked = true;
+    MutexGuard {
+      mutex: self,
+      marker: PhantomData,
+    }
+  }
+
+  pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+    let mut state = self.state.lock().unwrap();
+    if state.is_locked {
+      return None;
+    }
+    state.is_locked = true;
+    Some(MutexGuard {
+      mutex: self,
+      marker: PhantomData,
+    })
+  }
+
+  pub fn get_mut(&mut self) -> &mut T {
+    self.value.get_mut()
+  }
+
+  pub fn into_inner(self) -> T {
+    self.value.into_inner()
+  }
+
+  fn unlock(&self) {
+    let mut state = self.state.lock().unwrap();
+    state.is_locked = false;
+    resume(&mut state.suspended_routines);
+  }
+}
+
+impl<T: Default> Default for Mutex<T> {
+  fn default() -> Self {
+    Mutex::new(T::default())
+  }
+}
+
+pub struct MutexGuard<'a, T> {
+  mutex: &'a Mutex<T>,
+  marker: PhantomData<*const ()>,
+}
+
+unsafe impl<T: Send> Send for MutexGuard<'_, T> {}
+unsafe impl<T: Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T> Deref for MutexGuard<'_, T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    unsafe { &*self.mutex.value.get() }
+  }
+}
+
+impl<T> DerefMut for MutexGuard<'_, T> {
+  fn deref_mut(&mut self) -> &mut T {
+    unsafe { &mut *self.mutex.value.get() }
+  }
+}
+
+impl<T> Drop for MutexGuard<'_, T> {
+  fn drop(&mut self) {
+    self.mutex.unlock();
+  }
+}
@@ -0,0 +1,102 @@
+use std::cell::UnsafeCell;
+use std::sync::PoisonError;
+
+use crate::threading::mutex::*;
+
+pub trait Lock: Default {
+  type Guard<'a>
+  where
+    Self: 'a;
+
+  fn acquire(&self) -> Self::Guard<'_>;
+}
+
+impl Lock for std::sync::Mutex<()> {
+  type Guard<'a> = std::sync::MutexGuard<'a, ()>;
+
+  fn acquire(&self) -> Self::Guard<'_> {
+    self.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl Lock for Mutex<()> {
+  type Guard<'a> = MutexGuard<'a, ()>;
+
+  fn acquire(&self) -> Self::Guard<'_> {
+    self.lock()
+  }
+}
+
+pub struct Sync<T, L: Lock = std::sync::Mutex<()>> {
+  lock: L,
+  value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send, L: Lock + Send> Send for Sync<T, L> {}
+unsafe impl<T: Send, L: Lock + std::marker::Sync> std::marker::Sync
+  for Sync<T, L>
+{
+}
+
+impl<T, L: Lock> Sync<T, L> {
+  pub fn new(value: T) -> Self {
+    Sync {
+      lock: L::default(),
+      value: UnsafeCell::new(value),
+    }
+  }
+
+  pub fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+    let _guard = self.lock.acquire();
+    f(unsafe { &*self.value.get() })
+  }
+
+  pub fn with_mut<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+    let _guard = self.lock.acquire();
+    f(unsafe { &mut *self.value.get() })
+  }
+
+  pub fn load(&self) -> T
+  where
+    T: Clone,
+  {
+    self.with(|value| value.clone())
+  }
+
+  pub fn store(&self, value: T) {
+    self.with_mut(|current| *current = value);
+  }
+
+  pub fn get_mut(&mut self) -> &mut T {
+    self.value.get_mut()
+  }
+
+  pub fn into_inner(self) -> T {
+    self.value.into_inner()
+  }
+}
+
+impl<T: Default, L: Lock> Default for Sync<T, L> {
+  fn default() -> Self {
+    Sync::new(T::default())
+  }
+}
+
+pub fn with_both<T, U, L: Lock, M: Lock, R, F: FnOnce(&mut T, &mut U) -> R>(
+  first: &Sync<T, L>,
+  second: &Sync<U, M>,
+  f: F,
+) -> R {
+  let first_address = first as *const Sync<T, L> as usize;
+  let second_address = second as *const Sync<U, M> as usize;
+  assert!(first_address != second_address);
+  let (_first_guard, _second_guard);
+  if first_address < second_address {
+    _first_guard = first.lock.acquire();
+    _second_guard = second.lock.acquire();
+  } else {
+    _second_guard = second.lock.acquire();
+    _first_guard = first.lock.acquire();
+  }
+  unsafe { f(&mut *first.value.get(), &mut *second.value.get()) }
+}
@@ -0,0 +1,103 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::routines::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimerResult {
+  Expired,
+  Canceled,
+}
+
+pub trait Timer: Send + Sync {
+  fn start(&self);
+
+  fn cancel(&self);
+
+  fn wait(&self) -> TimerResult;
+}
+
+pub trait TimerFactory: Send + Sync {
+  type Timer: Timer;
+
+  fn create(&self, interval: Duration) -> Self::Timer;
+}
+
+struct TimerState {
+  is_running: bool,
+  generation: u64,
+  result: TimerResult,
+  waiters: Vec<Promise<TimerResult, ()>>,
+}
+
+pub(crate) struct TimerCore {
+  state: Mutex<TimerState>,
+}
+
+impl TimerCore {
+  pub(crate) fn new() -> Self {
+    TimerCore {
+      state: Mutex::new(TimerState {
+        is_running: false,
+        generation: 0,
+        result: TimerResult::Canceled,
+        waiters: Vec::new(),
+      }),
+    }
+  }
+
+  pub(crate) fn start(&self) -> Option<u64> {
+    let mut state = self.state.lock().unwrap();
+    if state.is_running {
+      return None;
+    }
+    state.is_running = true;
+    state.generation += 1;
+    Some(state.generation)
+  }
+
+  pub(crate) fn expire(&self, generation: u64) {
+    self.finish(Some(generation), TimerResult::Expired);
+  }
+
+  pub(crate) fn trigger(&self) {
+    self.finish(None, TimerResult::Expired);
+  }
+
+  pub(crate) fn cancel(&self) {
+    self.finish(None, TimerResult::Canceled);
+  }
+
+  pub(crate) fn wait(&self) -> TimerResult {
+    let future = {
+      let mut state = self.state.lock().unwrap();
+      if !state.is_running {
+        return state.result;
+      }
+      let (promise, future) = Promise::new_link();
+      state.waiters.push(promise);
+      future
+    };
+    match future.cancellable_result() {
+      Some(result) => result.unwrap(),
+      None => TimerResult::Canceled,
+    }
+  }
+
+  fn finish(&self, generation: Option<u64>, result: TimerResult) {
+    let waiters = {
+      let mut state = self.state.lock().unwrap();
+      if !state.is_running
+        || generation.is_some_and(|generation| generation != state.generation)
+      {
+        return;
+      }
+      state.is_running = false;
+      state.result = result;
+      std::mem::take(&mut state.waiters)
+    };
+    for waiter in waiters {
+      waiter.resolve(result);
+    }
+  }
+}
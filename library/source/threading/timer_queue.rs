@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+use std::sync::Condvar;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+type TimerTask = Box<dyn FnOnce() + Send>;
+
+pub(crate) type TimerKey = (Instant, u64);
+
+struct TimerQueueData {
+  entries: BTreeMap<TimerKey, TimerTask>,
+  next_sequence: u64,
+}
+
+pub(crate) struct TimerQueue {
+  data: Mutex<TimerQueueData>,
+  entries_changed: Condvar,
+}
+
+impl TimerQueue {
+  fn new() -> Self {
+    TimerQueue {
+      data: Mutex::new(TimerQueueData {
+        entries: BTreeMap::new(),
+        next_sequence: 0,
+      }),
+      entries_changed: Condvar::new(),
+    }
+  }
+
+  pub(crate) fn schedule(
+    &self,
+    deadline: Instant,
+    task: TimerTask,
+  ) -> TimerKey {
+    let mut data = self.data.lock().unwrap();
+    let key = (deadline, data.next_sequence);
+    data.next_sequence += 1;
+    data.entries.insert(key, task);
+    self.entries_changed.notify_one();
+    key
+  }
+
+  pub(crate) fn cancel(&self, key: TimerKey) {
+    let task = self.data.lock().unwrap().entries.remove(&key);
+    drop(task);
+  }
+
+  fn run(&self) {
+    let mut data = self.data.lock().unwrap();
+    loop {
+      let Some(deadline) = data.entries.first_key_value().map(|(key, _)| key.0)
+      else {
+        data = self.entries_changed.wait(data).unwrap();
+        continue;
+      };
+      let now = Instant::now();
+      if deadline > now {
+        data = self
+          .entries_changed
+          .wait_timeout(data, deadline - now)
+          .unwrap()
+          .0;
+        continue;
+      }
+      let (_, task) = data.entries.pop_first().unwrap();
+      drop(data);
+      task();
+      data = self.data.lock().unwrap();
+    }
+  }
+}
+
+static TIMER_QUEUE: OnceLock<TimerQueue> = OnceLock::new();
+
+pub(crate) fn get_timer_queue() -> &'static TimerQueue {
+  TIMER_QUEUE.get_or_init(|| {
+    std::thread::Builder::new()
+      .name(String::from("beam-timer"))
+      .spawn(|| get_timer_queue().run())
+      .unwrap();
+    TimerQueue::new()
+  })
+}
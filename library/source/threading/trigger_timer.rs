@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::threading::timer::*;
+
+#[derive(Clone)]
+pub struct TriggerTimer {
+  interval: Duration,
+  core: Arc<TimerCore>,
+}
+
+impl TriggerTimer {
+  pub fn new() -> Self {
+    TriggerTimer::with_interval(Duration::ZERO)
+  }
+
+  pub fn with_interval(interval: Duration) -> Self {
+    TriggerTimer {
+      interval,
+      core: Arc::new(TimerCore::new()),
+    }
+  }
+
+  pub fn interval(&self) -> Duration {
+    self.interval
+  }
+
+  pub fn trigger(&self) {
+    self.core.trigger();
+  }
+}
+
+impl Default for TriggerTimer {
+  fn default() -> Self {
+    TriggerTimer::new()
+  }
+}
+
+impl Timer for TriggerTimer {
+  fn start(&self) {
+    self.core.start();
+  }
+
+  fn cancel(&self) {
+    self.core.cancel();
+  }
+
+  fn wait(&self) -> TimerResult {
+    self.core.wait()
+  }
+}
+
+#[derive(Default)]
+pub struct TriggerTimerFactory {
+  timers: Mutex<Vec<TriggerTimer>>,
+}
+
+impl TriggerTimerFactory {
+  pub fn new() -> Self {
+    TriggerTimerFactory::default()
+  }
+
+  pub fn timers(&self) -> Vec<TriggerTimer> {
+    self.timers.lock().unwrap().clone()
+  }
+
+  pub fn trigger_all(&self) {
+    for timer in self.timers() {
+      timer.trigger();
+    }
+  }
+}
+
+impl TimerFactory for TriggerTimerFactory {
+  type Timer = TriggerTimer;
+
+  fn create(&self, interval: Duration) -> TriggerTimer {
+    let timer = TriggerTimer::with_interval(interval);
+    self.timers.lock().unwrap().push(timer.clone());
+    timer
+  }
+}
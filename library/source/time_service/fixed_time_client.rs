@@ -0,0 +1,32 @@
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use crate::time_service::time_client::*;
+
+pub struct FixedTimeClient {
+  time: Mutex<SystemTime>,
+}
+
+impl FixedTimeClient {
+  pub fn new(time: SystemTime) -> Self {
+    FixedTimeClient {
+      time: Mutex::new(time),
+    }
+  }
+
+  pub fn set_time(&self, time: SystemTime) {
+    *self.time.lock().unwrap() = time;
+  }
+}
+
+impl Default for FixedTimeClient {
+  fn default() -> Self {
+    FixedTimeClient::new(SystemTime::UNIX_EPOCH)
+  }
+}
+
+impl TimeClient for FixedTimeClient {
+  fn get_time(&self) -> SystemTime {
+    *self.time.lock().unwrap()
+  }
+}
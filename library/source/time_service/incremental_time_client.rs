@@ -0,0 +1,34 @@
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::time_service::time_client::*;
+
+pub struct IncrementalTimeClient {
+  next_time: Mutex<SystemTime>,
+  increment: Duration,
+}
+
+impl IncrementalTimeClient {
+  pub fn new(initial_time: SystemTime, increment: Duration) -> Self {
+    IncrementalTimeClient {
+      next_time: Mutex::new(initial_time),
+      increment,
+    }
+  }
+}
+
+impl Default for IncrementalTimeClient {
+  fn default() -> Self {
+    IncrementalTimeClient::new(SystemTime::UNIX_EPOCH, Duration::from_secs(1))
+  }
+}
+
+impl TimeClient for IncrementalTimeClient {
+  fn get_time(&self) -> SystemTime {
+    let mut next_time = self.next_time.lock().unwrap();
+    let time = *next_time;
+    *next_time += self.increment;
+    time
+  }
+}
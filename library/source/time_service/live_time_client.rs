@@ -0,0 +1,18 @@
+use std::time::SystemTime;
+
+use crate::time_service::time_client::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LiveTimeClient;
+
+impl LiveTimeClient {
+  pub fn new() -> Self {
+    LiveTimeClient
+  }
+}
+
+impl TimeClient for LiveTimeClient {
+  fn get_time(&self) -> SystemTime {
+    SystemTime::now()
+  }
+}
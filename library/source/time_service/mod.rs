@@ -0,0 +1,13 @@
+mod fixed_time_client;
+mod incremental_time_client;
+mod live_time_client;
+mod ntp_time_client;
+mod offset_time_client;
+mod time_client;
+
+pub use fixed_time_client::*;
+pub use incremental_time_client::*;
+pub use live_time_client::*;
+pub use ntp_time_client::*;
+pub use offset_time_client::*;
+pub use time_client::*;
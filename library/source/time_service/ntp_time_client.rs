@@ -0,0 +1,138 @@
+use std::net::SocketAddr;
+use std::net::UdpSocket;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use crate::routines::*;
+use crate::time_service::time_client::*;
+
+const NTP_PACKET_SIZE: usize = 48;
+const NTP_UNIX_EPOCH_DELTA: u64 = 2_208_988_800;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct NtpTimeClient {
+  sources: Vec<SocketAddr>,
+  timeout: Duration,
+  offset: AtomicI64,
+}
+
+impl NtpTimeClient {
+  pub fn new(sources: Vec<SocketAddr>) -> Self {
+    NtpTimeClient::with_timeout(sources, DEFAULT_TIMEOUT)
+  }
+
+  pub fn with_timeout(sources: Vec<SocketAddr>, timeout: Duration) -> Self {
+    NtpTimeClient {
+      sources,
+      timeout,
+      offset: AtomicI64::new(0),
+    }
+  }
+
+  pub fn offset_nanos(&self) -> i64 {
+    self.offset.load(Ordering::Relaxed)
+  }
+
+  pub fn synchronize(&self) -> std::io::Result<()> {
+    let mut last_error = std::io::Error::new(
+      std::io::ErrorKind::NotFound,
+      "no time sources configured",
+    );
+    for source in self.sources.iter().copied() {
+      let timeout = self.timeout;
+      match spawn_blocking(move || query(source, timeout)).result() {
+        Ok(Ok(offset)) => {
+          self.offset.store(offset, Ordering::Relaxed);
+          return Ok(());
+        }
+        Ok(Err(error)) => last_error = error,
+        Err(_) => {
+          last_error = std::io::Error::other("time source query panicked")
+        }
+      }
+    }
+    Err(last_error)
+  }
+}
+
+impl TimeClient for NtpTimeClient {
+  fn get_time(&self) -> SystemTime {
+    apply_offset(SystemTime::now(), self.offset_nanos())
+  }
+}
+
+fn query(source: SocketAddr, timeout: Duration) -> std::io::Result<i64> {
+  let bind_address: SocketAddr = if source.is_ipv4() {
+    "0.0.0.0:0".parse().unwrap()
+  } else {
+    "[::]:0".parse().unwrap()
+  };
+  let socket = UdpSocket::bind(bind_address)?;
+  socket.connect(source)?;
+  let mut request = [0u8; NTP_PACKET_SIZE];
+  request[0] = 0x23;
+  let origin_time = SystemTime::now();
+  let origin_timestamp = to_ntp_timestamp(origin_time)?;
+  request[40..48].copy_from_slice(&origin_timestamp.to_be_bytes());
+  let deadline = Instant::now() + timeout;
+  socket.send(&request)?;
+  let mut response = [0u8; NTP_PACKET_SIZE];
+  loop {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+      return Err(std::io::Error::new(
+        std::io::ErrorKind::TimedOut,
+        "time source did not respond",
+      ));
+    }
+    socket.set_read_timeout(Some(remaining))?;
+    let size = match socket.recv(&mut response) {
+      Ok(size) => size,
+      Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => continue,
+      Err(error) => return Err(error),
+    };
+    if size >= NTP_PACKET_SIZE && response[24..32] == request[40..48] {
+      break;
+    }
+  }
+  let destination_time = SystemTime::now();
+  let receive_time = from_ntp_timestamp(read_timestamp(&response[32..40]));
+  let transmit_time = from_ntp_timestamp(read_timestamp(&response[40..48]));
+  let offset = (difference(receive_time, origin_time)
+    + difference(transmit_time, destination_time))
+    / 2;
+  Ok(offset as i64)
+}
+
+fn read_timestamp(bytes: &[u8]) -> u64 {
+  u64::from_be_bytes(bytes.try_into().unwrap())
+}
+
+fn to_ntp_timestamp(time: SystemTime) -> std::io::Result<u64> {
+  let since_epoch =
+    time.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| {
+      std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        "system time is before the Unix epoch",
+      )
+    })?;
+  let seconds = since_epoch.as_secs() + NTP_UNIX_EPOCH_DELTA;
+  let fraction = ((since_epoch.subsec_nanos() as u64) << 32) / 1_000_000_000;
+  Ok((seconds << 32) | fraction)
+}
+
+fn from_ntp_timestamp(timestamp: u64) -> SystemTime {
+  let seconds = (timestamp >> 32).saturating_sub(NTP_UNIX_EPOCH_DELTA);
+  let nanos = ((timestamp & 0xFFFF_FFFF) * 1_000_000_000) >> 32;
+  SystemTime::UNIX_EPOCH + Duration::new(seconds, nanos as u32)
+}
+
+fn difference(left: SystemTime, right: SystemTime) -> i128 {
+  match left.duration_since(right) {
+    Ok(duration) => duration.as_nanos() as i128,
+    Err(error) => -(error.duration().as_nanos() as i128),
+  }
+}
@@ -0,0 +1,44 @@
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+
+use crate::time_service::live_time_client::*;
+use crate::time_service::time_client::*;
+
+pub struct OffsetTimeClient<C: TimeClient = LiveTimeClient> {
+  client: C,
+  offset: AtomicI64,
+}
+
+impl<C: TimeClient> OffsetTimeClient<C> {
+  pub fn new(client: C, offset_nanos: i64) -> Self {
+    OffsetTimeClient {
+      client,
+      offset: AtomicI64::new(offset_nanos),
+    }
+  }
+
+  pub fn offset_nanos(&self) -> i64 {
+    self.offset.load(Ordering::Relaxed)
+  }
+
+  pub fn set_offset_nanos(&self, offset_nanos: i64) {
+    self.offset.store(offset_nanos, Ordering::Relaxed);
+  }
+}
+
+impl OffsetTimeClient<LiveTimeClient> {
+  pub fn local(offset_nanos: i64) -> Self {
+    OffsetTimeClient::new(LiveTimeClient, offset_nanos)
+  }
+}
+
+impl<C: TimeClient> TimeClient for OffsetTimeClient<C> {
+  fn get_time(&self) -> SystemTime {
+    apply_offset(self.client.get_time(), self.offset_nanos())
+  }
+
+  fn close(&self) {
+    self.client.close();
+  }
+}
@@ -0,0 +1,16 @@
+use std::time::Duration;
+use std::time::SystemTime;
+
+pub trait TimeClient: Send + Sync {
+  fn get_time(&self) -> SystemTime;
+
+  fn close(&self) {}
+}
+
+pub(crate) fn apply_offset(time: SystemTime, offset: i64) -> SystemTime {
+  if offset >= 0 {
+    time + Duration::from_nanos(offset as u64)
+  } else {
+    time - Duration::from_nanos(offset.unsigned_abs())
+  }
+}
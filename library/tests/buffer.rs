@@ -0,0 +1,58 @@
+use beam::io::*;
+
+#[test]
+fn clones_share_data_until_written() {
+  let mut original = Buffer::from("hello");
+  let copy = original.clone();
+  assert!(original.is_shared());
+  assert_eq!(original.as_ptr(), copy.as_ptr());
+  original.as_mut_slice()[0] = b'j';
+  assert!(!original.is_shared());
+  assert!(!copy.is_shared());
+  assert_eq!(&original[..], b"jello");
+  assert_eq!(&copy[..], b"hello");
+}
+
+#[test]
+fn slices_copy_on_write_without_touching_the_parent() {
+  let parent = Buffer::from("hello world");
+  let mut slice = parent.slice(6..);
+  assert_eq!(&slice[..], b"world");
+  assert_eq!(slice.capacity(), 5);
+  slice.append(b"!");
+  slice.put::<u8>(0, b'W');
+  assert_eq!(&slice[..], b"World!");
+  assert_eq!(&parent[..], b"hello world");
+  assert!(!parent.is_shared());
+}
+
+#[test]
+fn consumed_prefixes_are_reused_before_growing() {
+  let mut buffer = Buffer::with_capacity(64);
+  buffer.append(&[1; 32]);
+  let start = buffer.as_ptr();
+  buffer.shrink_front(24);
+  assert_eq!(buffer.capacity(), 40);
+  buffer.append(&[2; 24]);
+  assert_eq!(buffer.as_ptr(), start.wrapping_add(24));
+  assert_eq!(buffer.len(), 32);
+  buffer.append(&[3; 16]);
+  assert_eq!(buffer.as_ptr(), start);
+  assert_eq!(&buffer[..8], &[1; 8]);
+  assert_eq!(&buffer[8..32], &[2; 24]);
+  assert_eq!(&buffer[32..], &[3; 16]);
+}
+
+#[test]
+fn resizing_and_shrinking_respect_the_offset() {
+  let mut buffer = Buffer::from("abcdef");
+  buffer.shrink_front(2);
+  buffer.resize(6);
+  assert_eq!(&buffer[..], b"cdef\0\0");
+  buffer.shrink(2);
+  buffer.push::<u16>(0x4847);
+  assert_eq!(&buffer[..], b"cdefGH");
+  buffer.shrink_to_fit();
+  assert_eq!(buffer.capacity(), 6);
+  assert_eq!(buffer.into_vec(), b"cdefGH");
+}
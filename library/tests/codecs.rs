@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use beam::codecs::*;
+use beam::io::*;
+use beam::routines::*;
+
+fn round_trip(codec_type: CodecType) {
+  let source = PipedReader::new();
+  let writer = CodedWriter::new(PipedWriter::new(&source), NullEncoder);
+  writer.set_encoder(codec_type.encoder());
+  let reader = CodedReader::new(&source, NullDecoder);
+  reader.set_decoder(codec_type.decoder());
+  let messages = ["hello ", "", "world", "!"];
+  for message in messages {
+    writer.write(&Buffer::from(message)).unwrap();
+  }
+  for message in messages {
+    let mut buffer = Buffer::new();
+    assert_eq!(
+      reader.read_message(&mut buffer).unwrap(),
+      Some(message.len())
+    );
+    assert_eq!(&buffer[..], message.as_bytes());
+  }
+  for message in messages {
+    writer.write(&Buffer::from(message)).unwrap();
+  }
+  let buffered_reader = BufferedReader::new(&reader);
+  let mut buffer = Buffer::new();
+  buffered_reader.read_exact(&mut buffer, 12).unwrap();
+  assert_eq!(&buffer[..], b"hello world!");
+  writer.destination().close().unwrap();
+  assert_eq!(buffered_reader.read(&mut buffer).unwrap(), 0);
+  assert_eq!(reader.read_message(&mut buffer).unwrap(), None);
+}
+
+#[test]
+fn null_codec_round_trips_several_messages() {
+  round_trip(CodecType::Null);
+}
+
+#[test]
+fn zlib_codec_round_trips_several_messages() {
+  round_trip(CodecType::Zlib);
+}
+
+#[test]
+fn oversized_decompressed_messages_are_rejected() {
+  let source = PipedReader::new();
+  let writer = CodedWriter::new(PipedWriter::new(&source), ZlibEncoder::new());
+  let reader =
+    CodedReader::with_max_message_size(&source, ZlibDecoder::new(), 1 << 20);
+  writer.write(&Buffer::from(vec![0; 1 << 20])).unwrap();
+  writer.write(&Buffer::from(vec![0; (1 << 20) + 1])).unwrap();
+  let mut message = Buffer::new();
+  assert_eq!(reader.read(&mut message).unwrap(), 1 << 20);
+  let error = reader.read(&mut message).unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+fn negotiate(
+  channel: LocalChannel,
+  preferences: &'static [CodecType],
+) -> (u64, Arc<Mutex<Option<(CodecType, CodecType)>>>) {
+  let result = Arc::new(Mutex::new(None));
+  let routine_result = result.clone();
+  let routine = spawn(move || {
+    let reader = CodedReader::new(channel.reader(), NullDecoder);
+    let writer = CodedWriter::new(channel.writer(), NullEncoder);
+    let codecs =
+      negotiate_connection_codecs(&reader, &writer, preferences).unwrap();
+    writer.write(&Buffer::from("ping")).unwrap();
+    let mut message = Buffer::new();
+    reader.read(&mut message).unwrap();
+    assert_eq!(&message[..], b"ping");
+    *routine_result.lock().unwrap() = Some(codecs);
+  });
+  (routine, result)
+}
+
+#[test]
+fn connections_negotiate_codecs() {
+  let server = LocalServerConnection::new("codecs-negotiation").unwrap();
+  let client = LocalChannel::connect("codecs-negotiation").unwrap();
+  let channel = server.accept().unwrap();
+  let (client_routine, client_result) =
+    negotiate(client, &[CodecType::Zlib, CodecType::Null]);
+  let (server_routine, server_result) =
+    negotiate(channel, &[CodecType::Null, CodecType::Zlib]);
+  wait_all(&[client_routine, server_routine]);
+  assert_eq!(
+    *client_result.lock().unwrap(),
+    Some((CodecType::Zlib, CodecType::Null))
+  );
+  assert_eq!(
+    *server_result.lock().unwrap(),
+    Some((CodecType::Null, CodecType::Zlib))
+  );
+}
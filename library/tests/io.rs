@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use beam::io::*;
+use beam::routines::*;
+
+#[test]
+fn adapters_layer_on_channels() {
+  let server = LocalServerConnection::new("io-adapters").unwrap();
+  let client = LocalChannel::connect("io-adapters").unwrap();
+  let channel = server.accept().unwrap();
+  let writer = SizeDeclarativeWriter::new(client.writer());
+  let reader =
+    SizeDeclarativeReader::new(BufferedReader::new(channel.reader()));
+  writer.write(&Buffer::from("hello")).unwrap();
+  let mut message = Buffer::new();
+  reader.read(&mut message).unwrap();
+  assert_eq!(&message[..], b"hello");
+}
+
+#[test]
+fn adapters_layer_on_shared_sources() {
+  let source = Arc::new(PipedReader::new());
+  let writer = PipedWriter::new(&source);
+  let reader = BufferedReader::new(source.clone());
+  writer.write(&Buffer::from("abc")).unwrap();
+  let mut data = Buffer::new();
+  reader.read_exact(&mut data, 3).unwrap();
+  assert_eq!(&data[..], b"abc");
+}
+
+#[test]
+fn empty_messages_are_preserved() {
+  let source = PipedReader::new();
+  let writer = SizeDeclarativeWriter::new(PipedWriter::new(&source));
+  let reader = SizeDeclarativeReader::new(&source);
+  writer.write(&Buffer::new()).unwrap();
+  writer.write(&Buffer::from("abc")).unwrap();
+  let mut message = Buffer::new();
+  assert_eq!(reader.read_message(&mut message).unwrap(), Some(0));
+  assert_eq!(reader.read_message(&mut message).unwrap(), Some(3));
+  assert_eq!(&message[..], b"abc");
+  writer.destination().close().unwrap();
+  assert_eq!(reader.read_message(&mut message).unwrap(), None);
+}
+
+#[test]
+fn empty_frames_are_invisible_to_byte_readers() {
+  let source = PipedReader::new();
+  let writer = SizeDeclarativeWriter::new(PipedWriter::new(&source));
+  let reader = BufferedReader::new(SizeDeclarativeReader::new(&source));
+  writer.write(&Buffer::new()).unwrap();
+  writer.write(&Buffer::from("abc")).unwrap();
+  writer.write(&Buffer::new()).unwrap();
+  let mut data = Buffer::new();
+  reader.read_exact(&mut data, 3).unwrap();
+  assert_eq!(&data[..], b"abc");
+  writer.destination().close().unwrap();
+  assert_eq!(reader.read(&mut data).unwrap(), 0);
+}
+
+#[test]
+fn truncated_frames_are_unexpected_eof() {
+  let source = PipedReader::new();
+  let writer = PipedWriter::new(&source);
+  let reader = SizeDeclarativeReader::new(&source);
+  let mut frame = Buffer::new();
+  frame.push(4u32);
+  frame.append(b"ab");
+  writer.write(&frame).unwrap();
+  writer.close().unwrap();
+  let mut data = Buffer::new();
+  let error = reader.read(&mut data).unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn oversized_messages_are_rejected() {
+  let source = PipedReader::new();
+  let writer = SizeDeclarativeWriter::new(PipedWriter::new(&source));
+  let reader = SizeDeclarativeReader::with_max_message_size(&source, 4);
+  writer.write(&Buffer::from("abcd")).unwrap();
+  writer.write(&Buffer::from("abcde")).unwrap();
+  let mut message = Buffer::new();
+  assert_eq!(reader.read(&mut message).unwrap(), 4);
+  let error = reader.read(&mut message).unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn buffered_poll_does_not_wait_for_a_parked_read() {
+  let source = Arc::new(PipedReader::new());
+  let writer = PipedWriter::new(&source);
+  let reader = Arc::new(BufferedReader::new(source.clone()));
+  let result = Arc::new(std::sync::Mutex::new(Buffer::new()));
+  let routine = {
+    let reader = reader.clone();
+    let result = result.clone();
+    spawn(move || {
+      let mut data = Buffer::new();
+      reader.read_exact(&mut data, 3).unwrap();
+      *result.lock().unwrap() = data;
+    })
+  };
+  std::thread::sleep(std::time::Duration::from_millis(50));
+  assert!(!reader.poll().unwrap());
+  writer.write(&Buffer::from("abc")).unwrap();
+  wait(routine);
+  assert_eq!(&result.lock().unwrap()[..], b"abc");
+}
+
+struct GatedWriter {
+  gate: PipedReader,
+  writes: std::sync::Mutex<Vec<Buffer>>,
+}
+
+impl Writer for GatedWriter {
+  fn write(&self, data: &Buffer) -> std::io::Result<()> {
+    let mut signal = Buffer::new();
+    self.gate.read_exact(&mut signal, 1)?;
+    if signal[0] == 0 {
+      return Err(std::io::ErrorKind::BrokenPipe.into());
+    }
+    self.writes.lock().unwrap().push(data.clone());
+    Ok(())
+  }
+}
+
+#[test]
+fn pipelined_writes_report_flush_failures() {
+  let destination = Arc::new(GatedWriter {
+    gate: PipedReader::new(),
+    writes: std::sync::Mutex::new(Vec::new()),
+  });
+  let gate = PipedWriter::new(&destination.gate);
+  let writer = Arc::new(PipelinedWriter::new(destination.clone()));
+  let results = Arc::new(std::sync::Mutex::new(Vec::new()));
+  let mut routines = Vec::new();
+  for message in ["a", "b", "c"] {
+    let writer = writer.clone();
+    let results = results.clone();
+    routines.push(spawn(move || {
+      let result = writer.write(&Buffer::from(message));
+      results.lock().unwrap().push((message, result.is_ok()));
+    }));
+    std::thread::sleep(std::time::Duration::from_millis(20));
+  }
+  gate.write(&Buffer::from(&[1, 0][..])).unwrap();
+  wait_all(&routines);
+  let mut results = results.lock().unwrap().clone();
+  results.sort();
+  assert_eq!(results, [("a", true), ("b", false), ("c", false)]);
+  assert_eq!(*destination.writes.lock().unwrap(), [Buffer::from("a")]);
+  let error = writer.write(&Buffer::from("d")).unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::BrokenPipe);
+}
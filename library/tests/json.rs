@@ -0,0 +1,79 @@
+use beam::serialization::*;
+
+#[test]
+fn deeply_nested_input_is_rejected() {
+  let text = "[".repeat(200000);
+  assert!(JsonValue::parse(&text).is_err());
+  let nested = format!("{}{}", "[".repeat(10), "]".repeat(10));
+  assert!(JsonValue::parse(&nested).is_ok());
+  assert!(JsonValue::parse_with_max_depth(&nested, 9).is_err());
+}
+
+#[test]
+fn values_round_trip_through_text() {
+  let text = r#"{"a":[1,-2.5e3,true,null],"b":"x\"\né"}"#;
+  let value = JsonValue::parse(text).unwrap();
+  assert_eq!(JsonValue::parse(&value.to_string()).unwrap(), value);
+}
+
+#[test]
+fn numbers_follow_the_rfc_8259_grammar() {
+  for text in ["0", "-0", "10", "0.5", "-1.25e-3", "1E+2", "2e10"] {
+    assert_eq!(
+      JsonValue::parse(text),
+      Ok(JsonValue::Number(text.to_string())),
+      "{}",
+      text
+    );
+  }
+  for text in [
+    "01", "-01", "1.", "1.e5", ".5", "-", "+1", "1e", "1e+", "0x10", "1-2",
+    "--1",
+  ] {
+    assert!(JsonValue::parse(text).is_err(), "{}", text);
+  }
+}
+
+#[test]
+fn strings_reject_unescaped_control_characters() {
+  for text in ["\"a\nb\"", "\"\t\"", "\"\u{0}\"", "\"\u{1f}\""] {
+    assert!(JsonValue::parse(text).is_err(), "{:?}", text);
+  }
+  assert_eq!(
+    JsonValue::parse(r#""a\nb\u001f""#),
+    Ok(JsonValue::String("a\nb\u{1f}".to_string()))
+  );
+  assert_eq!(
+    JsonValue::parse("\"\u{7f}é\""),
+    Ok(JsonValue::String("\u{7f}é".to_string()))
+  );
+}
+
+#[test]
+fn non_finite_floats_survive_optional_round_trips() {
+  let values = vec![
+    Some(f64::NAN),
+    Some(f64::INFINITY),
+    Some(f64::NEG_INFINITY),
+    Some(1.5),
+    None,
+  ];
+  let received: Vec<Option<f64>> = round_trip_json(&values).unwrap();
+  assert!(received[0].unwrap().is_nan());
+  assert_eq!(
+    received[1..],
+    [
+      Some(f64::INFINITY),
+      Some(f64::NEG_INFINITY),
+      Some(1.5),
+      None
+    ]
+  );
+  let received: Option<f32> = round_trip_json(&Some(f32::NAN)).unwrap();
+  assert!(received.unwrap().is_nan());
+  let mut receiver = JsonReceiver::parse(r#"{"value":null}"#).unwrap();
+  receiver.start_structure("").unwrap();
+  assert!(receiver.receive_f64("value").unwrap().is_nan());
+  let mut receiver = JsonReceiver::parse(r#""Infinite""#).unwrap();
+  assert!(receiver.receive_f64("value").is_err());
+}
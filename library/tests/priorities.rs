@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::ThreadId;
+
+use beam::routines::*;
+
+const STARVATION_LIMIT: usize = 8;
+
+type Record = Arc<Mutex<Vec<(ThreadId, RoutinePriority)>>>;
+
+struct Blockers {
+  is_released: Arc<AtomicBool>,
+  routines: Vec<u64>,
+}
+
+impl Blockers {
+  fn occupy_every_context() -> Self {
+    let thread_count = std::thread::available_parallelism().unwrap().get();
+    let is_released = Arc::new(AtomicBool::new(false));
+    let threads = Arc::new(Mutex::new(HashSet::new()));
+    let mut routines = Vec::new();
+    while threads.lock().unwrap().len() < thread_count {
+      routines.push(spawn({
+        let is_released = is_released.clone();
+        let threads = threads.clone();
+        move || {
+          if is_released.load(Ordering::SeqCst) {
+            return;
+          }
+          threads.lock().unwrap().insert(std::thread::current().id());
+          while !is_released.load(Ordering::SeqCst) {
+            std::hint::spin_loop();
+          }
+        }
+      }));
+      for _ in 0..1000 {
+        if threads.lock().unwrap().len() == routines.len() {
+          break;
+        }
+        std::thread::yield_now();
+      }
+    }
+    Blockers {
+      is_released,
+      routines,
+    }
+  }
+
+  fn release(self) {
+    self.is_released.store(true, Ordering::SeqCst);
+    wait_all(&self.routines);
+  }
+}
+
+fn spawn_recorded(record: &Record, priority: RoutinePriority) -> u64 {
+  let record = record.clone();
+  spawn_with_priority(
+    move || {
+      let thread = std::thread::current().id();
+      record.lock().unwrap().push((thread, priority));
+    },
+    priority,
+  )
+}
+
+fn by_thread(record: &Record) -> HashMap<ThreadId, Vec<RoutinePriority>> {
+  let mut threads = HashMap::<_, Vec<_>>::new();
+  for (thread, priority) in record.lock().unwrap().iter() {
+    threads.entry(*thread).or_default().push(*priority);
+  }
+  threads
+}
+
+fn release_and_wait(blockers: Blockers, routines: &[u64], record: &Record) {
+  blockers.release();
+  wait_all(routines);
+  while record.lock().unwrap().len() < routines.len() {
+    std::thread::yield_now();
+  }
+}
+
+#[test]
+fn pending_routines_run_by_priority_without_starving_low_priorities() {
+  let thread_count = std::thread::available_parallelism().unwrap().get();
+  let record = Record::default();
+  let blockers = Blockers::occupy_every_context();
+  let mut routines = Vec::new();
+  for priority in [
+    RoutinePriority::Low,
+    RoutinePriority::Normal,
+    RoutinePriority::High,
+  ] {
+    for _ in 0..thread_count {
+      routines.push(spawn_recorded(&record, priority));
+    }
+  }
+  release_and_wait(blockers, &routines, &record);
+  for priorities in by_thread(&record).into_values() {
+    let mut sorted = priorities.clone();
+    sorted.sort_by(|left, right| right.cmp(left));
+    assert_eq!(priorities, sorted);
+  }
+
+  let record = Record::default();
+  let blockers = Blockers::occupy_every_context();
+  let mut routines = Vec::new();
+  for _ in 0..thread_count {
+    routines.push(spawn_recorded(&record, RoutinePriority::Low));
+  }
+  for _ in 0..4 * STARVATION_LIMIT * thread_count {
+    routines.push(spawn_recorded(&record, RoutinePriority::High));
+  }
+  release_and_wait(blockers, &routines, &record);
+  let mut is_guarded = false;
+  for priorities in by_thread(&record).into_values() {
+    let Some(low) = priorities
+      .iter()
+      .position(|priority| *priority == RoutinePriority::Low)
+    else {
+      continue;
+    };
+    assert!(low <= STARVATION_LIMIT, "{:?}", priorities);
+    is_guarded |= low < priorities.len() - 1;
+  }
+  assert!(is_guarded);
+}
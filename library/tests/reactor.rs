@@ -0,0 +1,154 @@
+#![cfg(target_os = "linux")]
+
+use std::collections::HashSet;
+use std::os::fd::AsRawFd;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use socket2::Domain;
+use socket2::Socket;
+use socket2::Type;
+
+use beam::io::*;
+use beam::routines::*;
+
+#[derive(Default)]
+struct SuspendedRoutines {
+  routines: Mutex<HashSet<u64>>,
+}
+
+impl SchedulerHooks for SuspendedRoutines {
+  fn on_suspend(&self, routine: u64, _: usize, _: Duration) {
+    self.routines.lock().unwrap().insert(routine);
+  }
+}
+
+fn hooks() -> &'static Arc<SuspendedRoutines> {
+  static HOOKS: OnceLock<Arc<SuspendedRoutines>> = OnceLock::new();
+  HOOKS.get_or_init(|| {
+    let hooks = Arc::new(SuspendedRoutines::default());
+    set_scheduler_hooks(Some(hooks.clone()));
+    hooks
+  })
+}
+
+fn wait_until_suspended(routine: u64) {
+  while !hooks().routines.lock().unwrap().contains(&routine) {
+    std::thread::yield_now();
+  }
+}
+
+struct Task<T> {
+  routine: u64,
+  result: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Task<T> {
+  fn is_complete(&self) -> bool {
+    self.result.lock().unwrap().is_some()
+  }
+
+  fn join(self) -> T {
+    wait(self.routine);
+    let result = self.result.lock().unwrap().take();
+    result.expect("Routine did not complete.")
+  }
+}
+
+fn start<T: Send + 'static>(f: impl FnOnce() -> T + 'static) -> Task<T> {
+  hooks();
+  let result = Arc::new(Mutex::new(None));
+  let routine_result = result.clone();
+  let routine = spawn(move || {
+    *routine_result.lock().unwrap() = Some(f());
+  });
+  wait_until_suspended(routine);
+  Task { routine, result }
+}
+
+fn run<T: Send + 'static>(f: impl FnOnce() -> T + 'static) -> T {
+  let result = Arc::new(Mutex::new(None));
+  let routine_result = result.clone();
+  wait(spawn(move || {
+    *routine_result.lock().unwrap() = Some(f());
+  }));
+  let result = result.lock().unwrap().take();
+  result.unwrap()
+}
+
+fn connect() -> (Arc<TcpSocketChannel>, Arc<TcpSocketChannel>) {
+  run(|| {
+    let server = TcpServerSocket::bind("127.0.0.1:0").unwrap();
+    let address = server.local_address().unwrap();
+    let client = TcpSocketChannel::connect(address).unwrap();
+    let accepted = server.accept().unwrap();
+    (Arc::new(client), Arc::new(accepted))
+  })
+}
+
+#[test]
+fn failed_arms_only_fail_the_caller() {
+  let (socket, _peer) = Socket::pair(Domain::UNIX, Type::STREAM, None).unwrap();
+  socket.set_nonblocking(true).unwrap();
+  let fd = socket.as_raw_fd();
+  let reader = start(move || wait_readable(fd));
+  let (replacement, _) =
+    Socket::pair(Domain::UNIX, Type::STREAM, None).unwrap();
+  assert!(unsafe { libc::dup2(replacement.as_raw_fd(), fd) } >= 0);
+  let error = wait_writable(fd).unwrap_err();
+  assert_eq!(error.raw_os_error(), Some(libc::ENOENT));
+  assert!(!reader.is_complete());
+  deregister(fd);
+  let error = reader.join().unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::NotConnected);
+}
+
+#[test]
+fn socket_reads_retry_after_would_block() {
+  let (client, server) = connect();
+  let reader = start({
+    let server = server.clone();
+    move || {
+      let mut data = Buffer::new();
+      server.reader().read_exact(&mut data, 5).map(|_| data)
+    }
+  });
+  run(move || client.writer().write(&Buffer::from("hello")).unwrap());
+  assert_eq!(&reader.join().unwrap()[..], b"hello");
+}
+
+#[test]
+fn socket_writes_retry_after_would_block() {
+  let (client, server) = connect();
+  let size = 16 * 1024 * 1024;
+  let writer = start({
+    let client = client.clone();
+    move || client.writer().write(&Buffer::from(vec![7u8; size]))
+  });
+  let received = run(move || {
+    let mut data = Buffer::new();
+    server
+      .reader()
+      .read_exact(&mut data, size)
+      .map(|_| data.len())
+  });
+  writer.join().unwrap();
+  assert_eq!(received.unwrap(), size);
+}
+
+#[test]
+fn closing_a_socket_wakes_parked_routines() {
+  let (client, _server) = connect();
+  let reader = start({
+    let client = client.clone();
+    move || {
+      let mut data = Buffer::new();
+      client.reader().read(&mut data)
+    }
+  });
+  client.connection().close().unwrap();
+  let error = reader.join().unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::NotConnected);
+}
@@ -0,0 +1,173 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use beam::routines::*;
+use beam::threading::*;
+
+fn wait_for(flag: &AtomicBool) {
+  while !flag.load(Ordering::SeqCst) {
+    std::thread::yield_now();
+  }
+}
+
+#[test]
+fn group_failure_wakes_suspended_siblings() {
+  let group = RoutineGroup::new();
+  let timer = TriggerTimer::new();
+  timer.start();
+  let is_started = Arc::new(AtomicBool::new(false));
+  let result = Arc::new(Mutex::new(None));
+  group.spawn({
+    let timer = timer.clone();
+    let is_started = is_started.clone();
+    let result = result.clone();
+    move || {
+      is_started.store(true, Ordering::SeqCst);
+      *result.lock().unwrap() = Some(timer.wait());
+      Ok(())
+    }
+  });
+  wait_for(&is_started);
+  let watchdog = {
+    let timer = timer.clone();
+    std::thread::spawn(move || {
+      std::thread::sleep(Duration::from_secs(5));
+      timer.trigger();
+    })
+  };
+  group.spawn(|| Err("failed"));
+  assert_eq!(group.wait_all(), Err("failed"));
+  assert_eq!(*result.lock().unwrap(), Some(TimerResult::Canceled));
+  drop(watchdog);
+}
+
+#[test]
+fn group_failure_interrupts_sleeping_siblings() {
+  let group = RoutineGroup::new();
+  let is_started = Arc::new(AtomicBool::new(false));
+  group.spawn({
+    let is_started = is_started.clone();
+    move || {
+      is_started.store(true, Ordering::SeqCst);
+      sleep(Duration::from_secs(5));
+      Ok(())
+    }
+  });
+  wait_for(&is_started);
+  let start = Instant::now();
+  group.spawn(|| Err(()));
+  assert_eq!(group.wait_all(), Err(()));
+  assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn group_reports_the_first_failure() {
+  let group = RoutineGroup::new();
+  let timer = TriggerTimer::new();
+  timer.start();
+  group.spawn(move || {
+    timer.wait();
+    Err(2)
+  });
+  group.spawn(|| Err(1));
+  assert_eq!(group.wait_all(), Err(1));
+  assert_eq!(group.wait_all(), Ok(()));
+}
+
+#[test]
+fn group_propagates_panics() {
+  let result = std::panic::catch_unwind(|| {
+    let group = RoutineGroup::<()>::new();
+    group.spawn(|| panic!("routine panicked"));
+    group.wait_all()
+  });
+  let payload = result.unwrap_err();
+  assert_eq!(payload.downcast_ref::<&str>(), Some(&"routine panicked"));
+}
+
+#[test]
+fn scoped_routines_borrow_from_the_parent() {
+  let mut values = vec![0; 4];
+  let total = scope(|scope| {
+    for (index, value) in values.iter_mut().enumerate() {
+      scope.spawn(move || {
+        *value = index * 2;
+        Ok::<(), ()>(())
+      });
+    }
+    "spawned"
+  });
+  assert_eq!(total, Ok("spawned"));
+  assert_eq!(values, [0, 2, 4, 6]);
+}
+
+#[test]
+fn cancelled_routines_stop_waiting_on_futures() {
+  let (promise, future) = Promise::<u32, ()>::new_link();
+  let is_started = Arc::new(AtomicBool::new(false));
+  let result = Arc::new(Mutex::new(None));
+  let routine = spawn({
+    let is_started = is_started.clone();
+    let result = result.clone();
+    move || {
+      is_started.store(true, Ordering::SeqCst);
+      *result.lock().unwrap() = Some(future.cancellable_result());
+    }
+  });
+  wait_for(&is_started);
+  cancel(routine);
+  wait(routine);
+  assert_eq!(*result.lock().unwrap(), Some(None));
+  promise.resolve(1);
+}
+
+#[test]
+fn task_queues_run_tasks_in_order() {
+  let queue = TaskQueue::new();
+  let order = Arc::new(Mutex::new(Vec::new()));
+  for index in 0..100 {
+    let order = order.clone();
+    queue.push(move || order.lock().unwrap().push(index));
+  }
+  let (promise, future) = Promise::<(), ()>::new_link();
+  queue.push(move || promise.resolve(()));
+  assert_eq!(future.result(), Ok(()));
+  assert_eq!(*order.lock().unwrap(), (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn task_queues_continue_after_a_panicking_task() {
+  let queue = TaskQueue::new();
+  let is_run = Arc::new(AtomicBool::new(false));
+  queue.push(|| panic!("task failure"));
+  queue.push({
+    let is_run = is_run.clone();
+    move || is_run.store(true, Ordering::SeqCst)
+  });
+  wait_for(&is_run);
+  while !queue.is_empty() {
+    std::thread::yield_now();
+  }
+  let (promise, future) = Promise::<(), ()>::new_link();
+  queue.push(move || promise.resolve(()));
+  assert_eq!(future.result(), Ok(()));
+}
+
+#[test]
+fn spawn_blocking_returns_results_from_the_blocking_pool() {
+  let thread_name =
+    spawn_blocking(|| std::thread::current().name().map(String::from));
+  assert_eq!(
+    thread_name.result().unwrap().as_deref(),
+    Some("beam-blocking")
+  );
+  assert_eq!(spawn_blocking(|| 42).result().ok(), Some(42));
+  let payload = spawn_blocking(|| -> u32 { panic!("blocking failure") })
+    .result()
+    .unwrap_err();
+  assert_eq!(payload.downcast_ref::<&str>(), Some(&"blocking failure"));
+}
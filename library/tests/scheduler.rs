@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use beam::routines::*;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Event {
+  Spawn,
+  Resume,
+  Suspend,
+  Complete,
+  Overrun,
+}
+
+#[derive(Default)]
+struct RecordingHooks {
+  events: Mutex<HashMap<u64, Vec<Event>>>,
+}
+
+impl RecordingHooks {
+  fn record(&self, routine: u64, event: Event) {
+    self
+      .events
+      .lock()
+      .unwrap()
+      .entry(routine)
+      .or_default()
+      .push(event);
+  }
+
+  fn events(&self, routine: u64) -> Vec<Event> {
+    self
+      .events
+      .lock()
+      .unwrap()
+      .get(&routine)
+      .cloned()
+      .unwrap_or_default()
+  }
+}
+
+impl SchedulerHooks for RecordingHooks {
+  fn on_spawn(&self, routine: u64, _: usize) {
+    self.record(routine, Event::Spawn);
+  }
+
+  fn on_resume(&self, routine: u64, _: usize) {
+    self.record(routine, Event::Resume);
+  }
+
+  fn on_suspend(&self, routine: u64, _: usize, _: Duration) {
+    self.record(routine, Event::Suspend);
+  }
+
+  fn on_complete(&self, routine: u64, _: usize, _: Duration) {
+    self.record(routine, Event::Complete);
+  }
+
+  fn on_overrun(&self, routine: u64, _: usize, _: Duration, _: Duration) {
+    self.record(routine, Event::Overrun);
+  }
+}
+
+fn hooks() -> &'static Arc<RecordingHooks> {
+  static HOOKS: OnceLock<Arc<RecordingHooks>> = OnceLock::new();
+  HOOKS.get_or_init(|| {
+    let hooks = Arc::new(RecordingHooks::default());
+    set_scheduler_hooks(Some(hooks.clone()));
+    hooks
+  })
+}
+
+fn wait_for_events(routine: u64, event: Event, count: usize) {
+  let hooks = hooks();
+  while hooks
+    .events(routine)
+    .iter()
+    .filter(|recorded| **recorded == event)
+    .count()
+    < count
+  {
+    std::thread::yield_now();
+  }
+}
+
+struct Task<T> {
+  routine: u64,
+  result: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Task<T> {
+  fn join(self) -> T {
+    wait_for_events(self.routine, Event::Complete, 1);
+    let result = self.result.lock().unwrap().take();
+    result.expect("Routine did not complete.")
+  }
+}
+
+fn start<T: Send + 'static>(f: impl FnOnce() -> T + 'static) -> Task<T> {
+  hooks();
+  let result = Arc::new(Mutex::new(None));
+  let routine_result = result.clone();
+  let routine = spawn(move || {
+    *routine_result.lock().unwrap() = Some(f());
+  });
+  Task { routine, result }
+}
+
+fn start_suspended<T: Send + 'static>(
+  f: impl FnOnce() -> T + 'static,
+) -> Task<T> {
+  let task = start(f);
+  wait_for_events(task.routine, Event::Suspend, 1);
+  task
+}
+
+fn spawn_gated(f: impl FnOnce() + 'static) -> (u64, Promise<(), ()>) {
+  let (promise, future) = Promise::new_link();
+  let task = start_suspended(move || {
+    let _ = future.result();
+    f();
+  });
+  (task.routine, promise)
+}
+
+#[test]
+fn wait_reports_how_routines_finished() {
+  let (complete, complete_gate) = spawn_gated(|| {});
+  let (panicked, panicked_gate) = spawn_gated(|| panic!("routine failure"));
+  let (cancelled, cancelled_gate) = spawn_gated(|| {});
+  let waiters = [complete, panicked, cancelled]
+    .map(|routine| start_suspended(move || wait(routine)));
+  cancel(cancelled);
+  complete_gate.resolve(());
+  panicked_gate.resolve(());
+  cancelled_gate.resolve(());
+  assert_eq!(
+    waiters.map(Task::join),
+    [
+      RoutineStatus::Complete,
+      RoutineStatus::Panicked,
+      RoutineStatus::Cancelled,
+    ]
+  );
+  assert_eq!(wait(complete), RoutineStatus::NotFound);
+  assert_eq!(wait(u64::MAX), RoutineStatus::NotFound);
+}
+
+#[test]
+fn wait_any_reports_the_first_routine_to_finish() {
+  let (first, first_gate) = spawn_gated(|| {});
+  let (second, second_gate) = spawn_gated(|| {});
+  let waiter = start_suspended(move || wait_any(&[first, second]));
+  second_gate.resolve(());
+  assert_eq!(waiter.join(), (second, RoutineStatus::Complete));
+  assert_eq!(
+    wait_any(&[first, u64::MAX]),
+    (u64::MAX, RoutineStatus::NotFound)
+  );
+  first_gate.resolve(());
+}
+
+#[test]
+fn wait_all_reports_every_routine() {
+  let (first, first_gate) = spawn_gated(|| {});
+  let (second, second_gate) = spawn_gated(|| panic!("routine failure"));
+  let waiter = start_suspended(move || wait_all(&[first, second]));
+  first_gate.resolve(());
+  wait_for_events(waiter.routine, Event::Suspend, 2);
+  second_gate.resolve(());
+  assert_eq!(
+    waiter.join(),
+    [RoutineStatus::Complete, RoutineStatus::Panicked]
+  );
+}
+
+#[test]
+fn hooks_and_metrics_follow_the_routine_lifecycle() {
+  let before = scheduler_metrics();
+  let (routine, gate) = spawn_gated(|| {});
+  gate.resolve(());
+  wait_for_events(routine, Event::Complete, 1);
+  assert_eq!(
+    hooks().events(routine),
+    [
+      Event::Spawn,
+      Event::Resume,
+      Event::Suspend,
+      Event::Resume,
+      Event::Complete,
+    ]
+  );
+  let after = scheduler_metrics();
+  assert!(after.timestamp > before.timestamp);
+  assert!(after.spawn_count > before.spawn_count);
+  assert!(after.complete_count > before.complete_count);
+  assert!(after.advance_count() >= before.advance_count() + 2);
+  assert_eq!(after.contexts.len(), before.contexts.len());
+}
+
+#[test]
+fn overruns_are_reported_through_hooks() {
+  let busy_time = yield_slice() * 12;
+  let task = start(move || {
+    let start = Instant::now();
+    while start.elapsed() < busy_time {
+      std::hint::spin_loop();
+    }
+  });
+  let routine = task.routine;
+  task.join();
+  assert!(hooks().events(routine).contains(&Event::Overrun));
+  let quick = start(|| {});
+  let routine = quick.routine;
+  quick.join();
+  assert!(!hooks().events(routine).contains(&Event::Overrun));
+}
+
+#[test]
+fn cancelled_routines_observe_the_cancellation() {
+  let (promise, future) = Promise::<(), ()>::new_link();
+  let task = start_suspended(move || {
+    let was_cancelled = is_cancelled();
+    let _ = future.result();
+    (was_cancelled, is_cancelled())
+  });
+  let waiter = {
+    let routine = task.routine;
+    start_suspended(move || wait(routine))
+  };
+  cancel(task.routine);
+  cancel(u64::MAX);
+  promise.resolve(());
+  assert_eq!(task.join(), (false, true));
+  assert_eq!(waiter.join(), RoutineStatus::Cancelled);
+}
+
+#[test]
+fn priorities_change_while_routines_run() {
+  let (promise, future) = Promise::<(), ()>::new_link();
+  let task = start_suspended(move || {
+    let initial = priority();
+    let _ = future.result();
+    (initial, priority())
+  });
+  set_priority(task.routine, RoutinePriority::High);
+  promise.resolve(());
+  assert_eq!(
+    task.join(),
+    (RoutinePriority::Normal, RoutinePriority::High)
+  );
+  let is_low = Arc::new(AtomicBool::new(false));
+  let routine = spawn_with_priority(
+    {
+      let is_low = is_low.clone();
+      move || is_low.store(priority() == RoutinePriority::Low, Ordering::SeqCst)
+    },
+    RoutinePriority::Low,
+  );
+  wait_for_events(routine, Event::Complete, 1);
+  assert!(is_low.load(Ordering::SeqCst));
+}
+
+#[test]
+fn futures_resolved_from_other_threads_resume_routines() {
+  let (tasks, promises): (Vec<_>, Vec<_>) = (0..64)
+    .map(|index| {
+      let (promise, future) = Promise::<u32, ()>::new_link();
+      (
+        start(move || future.result().map(|value| value + index)),
+        promise,
+      )
+    })
+    .unzip();
+  let resolver = std::thread::spawn(move || {
+    for promise in promises {
+      promise.resolve(1);
+    }
+  });
+  resolver.join().unwrap();
+  for (index, task) in tasks.into_iter().enumerate() {
+    assert_eq!(task.join(), Ok(index as u32 + 1));
+  }
+}
@@ -0,0 +1,186 @@
+use beam::serialization::*;
+use beam::service_locator::directory_entry::*;
+
+fn encode<T: Shuttle>(value: &T) -> Vec<u8> {
+  let mut sender = BinarySender::new();
+  value.send(&mut sender, "value");
+  sender.into_data()
+}
+
+fn seven() -> u32 {
+  7
+}
+
+#[derive(Debug, Default, PartialEq, Shuttle)]
+struct Skipped {
+  id: u32,
+  #[shuttle(skip)]
+  cache: Vec<u32>,
+  #[shuttle(skip, default = "seven")]
+  retries: u32,
+}
+
+#[derive(Debug, Default, PartialEq, Shuttle)]
+#[shuttle(version = 1)]
+struct AccountV1 {
+  id: u32,
+  name: String,
+}
+
+#[derive(Debug, Default, PartialEq, Shuttle)]
+#[shuttle(version = 2)]
+struct AccountV2 {
+  id: u32,
+  name: String,
+  #[shuttle(since = 2, default = "seven")]
+  limit: u32,
+}
+
+#[derive(Debug, PartialEq, Shuttle)]
+struct Pair<A, B> {
+  first: A,
+  second: Option<B>,
+}
+
+#[derive(Debug, PartialEq, Shuttle)]
+struct Wrapper<T>(T, u8);
+
+#[derive(Debug, PartialEq, Shuttle)]
+enum Request {
+  Ping,
+  Load(u32, String),
+  Store {
+    id: u32,
+    entries: Vec<DirectoryEntry>,
+  },
+}
+
+#[derive(Debug, PartialEq, Shuttle)]
+enum Color {
+  Red,
+  Green,
+  Blue,
+}
+
+#[derive(Debug, PartialEq, Shuttle)]
+enum Status {
+  Active = 10,
+  Suspended,
+  Closed = 3,
+}
+
+#[test]
+fn skipped_fields_use_defaults() {
+  let value = Skipped {
+    id: 3,
+    cache: vec![1, 2],
+    retries: 1,
+  };
+  let received = round_trip::<_, Skipped>(&value).unwrap();
+  assert_eq!(
+    received,
+    Skipped {
+      id: 3,
+      cache: Vec::new(),
+      retries: 7,
+    }
+  );
+}
+
+#[test]
+fn newer_fields_default_for_older_senders() {
+  let old = AccountV1 {
+    id: 5,
+    name: String::from("alice"),
+  };
+  let received = round_trip::<_, AccountV2>(&old).unwrap();
+  assert_eq!(
+    received,
+    AccountV2 {
+      id: 5,
+      name: String::from("alice"),
+      limit: 7,
+    }
+  );
+  assert_eq!(round_trip_json::<_, AccountV2>(&old).unwrap(), received);
+}
+
+#[test]
+fn older_receivers_skip_newer_fields() {
+  let new = AccountV2 {
+    id: 5,
+    name: String::from("alice"),
+    limit: 100,
+  };
+  let expected = AccountV1 {
+    id: 5,
+    name: String::from("alice"),
+  };
+  assert_eq!(round_trip::<_, AccountV1>(&new).unwrap(), expected);
+  assert_eq!(round_trip_json::<_, AccountV1>(&new).unwrap(), expected);
+}
+
+#[test]
+fn generic_types_round_trip() {
+  let value = Pair {
+    first: Wrapper(String::from("x"), 9),
+    second: Some(vec![1u64, 2, 3]),
+  };
+  assert_eq!(round_trip::<_, Pair<_, _>>(&value).unwrap(), value);
+  let empty = Pair::<u8, u8> {
+    first: 1,
+    second: None,
+  };
+  assert_eq!(round_trip_json::<_, Pair<_, _>>(&empty).unwrap(), empty);
+}
+
+#[test]
+fn data_enums_round_trip() {
+  for request in [
+    Request::Ping,
+    Request::Load(4, String::from("root")),
+    Request::Store {
+      id: 2,
+      entries: vec![DirectoryEntry::root_directory()],
+    },
+  ] {
+    assert_eq!(round_trip::<_, Request>(&request).unwrap(), request);
+    assert_eq!(round_trip_json::<_, Request>(&request).unwrap(), request);
+  }
+}
+
+#[test]
+fn unit_enums_are_sent_as_u32() {
+  assert_eq!(encode(&Color::Blue), encode(&2u32));
+  assert_eq!(encode(&DirectoryCategory::Account), encode(&1u32));
+  assert!(round_trip::<_, Color>(&3u32).is_err());
+}
+
+#[test]
+fn unit_enums_are_sent_as_their_declared_discriminants() {
+  assert_eq!(encode(&Status::Active), encode(&10u32));
+  assert_eq!(encode(&Status::Suspended), encode(&11u32));
+  assert_eq!(encode(&Status::Closed), encode(&3u32));
+  for status in [Status::Active, Status::Suspended, Status::Closed] {
+    assert_eq!(round_trip::<_, Status>(&status).unwrap(), status);
+    assert_eq!(round_trip_json::<_, Status>(&status).unwrap(), status);
+  }
+  assert_eq!(round_trip::<_, Status>(&11u32), Ok(Status::Suspended));
+  assert!(round_trip::<_, Status>(&0u32).is_err());
+  assert!(round_trip::<_, Status>(&2u32).is_err());
+}
+
+#[test]
+fn directory_entry_matches_manual_encoding() {
+  let entry = DirectoryEntry::new_account(9, String::from("bob"));
+  let mut sender = BinarySender::new();
+  sender.start_structure("value", 0);
+  sender.send_u32("category", 1);
+  sender.send_u32("id", 9);
+  sender.send_str("name", "bob");
+  sender.end_structure();
+  assert_eq!(encode(&entry), sender.into_data());
+  let received = round_trip::<_, DirectoryEntry>(&entry).unwrap();
+  assert_eq!(received.category, DirectoryCategory::Account);
+  assert_eq!(received.name, "bob");
+}
@@ -0,0 +1,295 @@
+#![cfg(target_os = "linux")]
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use beam::io::*;
+use beam::routines::*;
+
+struct Task<T> {
+  routine: u64,
+  result: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Task<T> {
+  fn join(self) -> T {
+    wait(self.routine);
+    let result = self.result.lock().unwrap().take();
+    result.expect("Routine did not complete.")
+  }
+}
+
+fn start<T: Send + 'static>(f: impl FnOnce() -> T + 'static) -> Task<T> {
+  let result = Arc::new(Mutex::new(None));
+  let routine_result = result.clone();
+  let routine = spawn(move || {
+    *routine_result.lock().unwrap() = Some(f());
+  });
+  Task { routine, result }
+}
+
+fn run<T: Send + 'static>(f: impl FnOnce() -> T + 'static) -> T {
+  start(f).join()
+}
+
+fn echo_once(server: TcpServerSocket) -> Task<()> {
+  start(move || {
+    let channel = server.accept().unwrap();
+    let mut data = Buffer::new();
+    channel.reader().read_exact(&mut data, 5).unwrap();
+    channel.writer().write(&data).unwrap();
+  })
+}
+
+#[test]
+fn tcp_channels_accept_over_loopback() {
+  let server = run(|| TcpServerSocket::bind("127.0.0.1:0").unwrap());
+  let address = server.local_address().unwrap();
+  let echo = echo_once(server);
+  let reply = run(move || {
+    let channel = TcpSocketChannel::connect(address).unwrap();
+    assert_eq!(*channel.identifier(), address);
+    channel.writer().write(&Buffer::from("hello")).unwrap();
+    let mut data = Buffer::new();
+    channel.reader().read_exact(&mut data, 5).unwrap();
+    data
+  });
+  echo.join();
+  assert_eq!(&reply[..], b"hello");
+}
+
+#[test]
+fn tcp_connect_falls_back_to_later_addresses() {
+  let closed_address = run(|| {
+    TcpServerSocket::bind("127.0.0.1:0")
+      .unwrap()
+      .local_address()
+      .unwrap()
+  });
+  let server = run(|| TcpServerSocket::bind("127.0.0.1:0").unwrap());
+  let address = server.local_address().unwrap();
+  let echo = echo_once(server);
+  let identifier = run(move || {
+    let addresses = [closed_address, address];
+    let channel = TcpSocketChannel::connect_addresses(
+      &addresses,
+      &TcpSocketOptions::default(),
+    )
+    .unwrap();
+    channel.writer().write(&Buffer::from("hello")).unwrap();
+    *channel.identifier()
+  });
+  echo.join();
+  assert_eq!(identifier, address);
+  let error = run(move || {
+    TcpSocketChannel::connect_addresses(
+      &[closed_address],
+      &TcpSocketOptions::default(),
+    )
+    .err()
+    .unwrap()
+    .kind()
+  });
+  assert_eq!(error, std::io::ErrorKind::ConnectionRefused);
+}
+
+#[test]
+fn tcp_options_apply_to_connected_and_accepted_sockets() {
+  let options = TcpSocketOptions {
+    no_delay: true,
+    keep_alive: Some(Duration::from_secs(60)),
+    receive_buffer_size: Some(64 * 1024),
+    ..TcpSocketOptions::default()
+  };
+  let server_options = options.clone();
+  let server = run(move || {
+    TcpServerSocket::bind_with_options("127.0.0.1:0", server_options).unwrap()
+  });
+  let address = server.local_address().unwrap();
+  let accepted = start(move || {
+    let channel = server.accept().unwrap();
+    let socket = socket2::SockRef::from(&channel);
+    assert!(socket.nodelay().unwrap());
+    assert!(socket.keepalive().unwrap());
+    assert_eq!(socket.keepalive_time().unwrap(), Duration::from_secs(60));
+  });
+  run(move || {
+    let channel = TcpSocketChannel::connect_with_options(
+      SocketAddr::from(([127, 0, 0, 1], address.port())),
+      &options,
+    )
+    .unwrap();
+    let socket = socket2::SockRef::from(&channel);
+    assert!(socket.nodelay().unwrap());
+    assert!(socket.keepalive().unwrap());
+    assert!(socket.recv_buffer_size().unwrap() >= 64 * 1024);
+  });
+  accepted.join();
+}
+
+#[test]
+fn cancelling_a_routine_interrupts_its_accept() {
+  let server = run(|| TcpServerSocket::bind("127.0.0.1:0").unwrap());
+  let is_started = Arc::new(std::sync::atomic::AtomicBool::new(false));
+  let accept = start({
+    let is_started = is_started.clone();
+    move || {
+      is_started.store(true, std::sync::atomic::Ordering::SeqCst);
+      server.accept().is_err()
+    }
+  });
+  while !is_started.load(std::sync::atomic::Ordering::SeqCst) {
+    std::thread::yield_now();
+  }
+  cancel(accept.routine);
+  assert!(accept.join());
+}
+
+#[test]
+fn udp_receives_append_into_spare_capacity() {
+  let (received, original, sender_address) = run(|| {
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let address = receiver.local_address().unwrap();
+    sender.send_to(b"hello", address).unwrap();
+    sender.send_to(b"world", address).unwrap();
+    let mut buffer = Buffer::from("> ");
+    let original = buffer.clone();
+    let (size, from) = receiver.receive_from(&mut buffer).unwrap();
+    assert_eq!(size, 5);
+    let (size, _) = receiver.receive_from(&mut buffer).unwrap();
+    assert_eq!(size, 5);
+    assert_eq!(from, sender.local_address().unwrap());
+    (buffer, original, from)
+  });
+  assert_eq!(&received[..], b"> helloworld");
+  assert_eq!(&original[..], b"> ");
+  assert_eq!(sender_address.ip(), std::net::Ipv4Addr::LOCALHOST);
+}
+
+#[test]
+fn udp_receives_the_largest_datagrams_whole() {
+  const MAX_UDP_PAYLOAD: usize = 65507;
+  let received = run(|| {
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    receiver
+      .set_receive_buffer_size(4 * MAX_DATAGRAM_SIZE)
+      .unwrap();
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+    sender.set_send_buffer_size(4 * MAX_DATAGRAM_SIZE).unwrap();
+    let address = receiver.local_address().unwrap();
+    let data = (0..MAX_UDP_PAYLOAD).map(|i| i as u8).collect::<Vec<_>>();
+    sender.send_to(&data, address).unwrap();
+    sender.send_to(b"next", address).unwrap();
+    let mut first = Buffer::new();
+    let (size, _) = receiver.receive_from(&mut first).unwrap();
+    assert_eq!(size, MAX_UDP_PAYLOAD);
+    assert_eq!(&first[..], &data[..]);
+    let mut second = Buffer::new();
+    receiver.receive_from(&mut second).unwrap();
+    second
+  });
+  assert_eq!(&received[..], b"next");
+}
+
+#[test]
+fn multicast_sockets_join_and_leave_groups() {
+  let group = std::net::IpAddr::from([239, 255, 77, 1]);
+  let interface = MulticastInterface::Address(std::net::Ipv4Addr::LOCALHOST);
+  let (received, rejoin, releave) = run(move || {
+    let receiver = MulticastSocket::bind(0, false).unwrap();
+    let port = receiver.local_address().unwrap().port();
+    receiver.join(group, interface).unwrap();
+    let rejoin = receiver.join(group, interface).is_err();
+    let sender = MulticastSocket::bind(0, false).unwrap();
+    sender.set_outgoing_interface(interface).unwrap();
+    sender.set_loopback(true).unwrap();
+    sender.set_ttl(0).unwrap();
+    sender
+      .send_to(b"hello", SocketAddr::new(group, port))
+      .unwrap();
+    let mut buffer = Buffer::new();
+    receiver.receive_from(&mut buffer).unwrap();
+    receiver.leave(group, interface).unwrap();
+    let releave = receiver.leave(group, interface).is_err();
+    (buffer, rejoin, releave)
+  });
+  assert_eq!(&received[..], b"hello");
+  assert!(rejoin);
+  assert!(releave);
+}
+
+#[test]
+fn unix_sockets_connect_in_the_abstract_namespace() {
+  let name = format!("@beam-test-{}", std::process::id());
+  let server = run({
+    let name = name.clone();
+    move || UnixServerSocket::bind(name.as_str()).unwrap()
+  });
+  assert_eq!(server.address().to_string(), name);
+  assert!(matches!(server.address(), UnixSocketAddress::Abstract(_)));
+  let accepted = start(move || {
+    let channel = server.accept().unwrap();
+    let mut data = Buffer::new();
+    channel.reader().read_exact(&mut data, 5).unwrap();
+    (data, channel.peer_credentials().unwrap())
+  });
+  let credentials = run(move || {
+    let channel = UnixSocketChannel::connect(name.as_str()).unwrap();
+    channel.writer().write(&Buffer::from("hello")).unwrap();
+    channel.peer_credentials().unwrap()
+  });
+  let (data, peer_credentials) = accepted.join();
+  assert_eq!(&data[..], b"hello");
+  for credentials in [credentials, peer_credentials] {
+    assert_eq!(credentials.pid, std::process::id() as i32);
+    assert_eq!(credentials.uid, unsafe { libc::getuid() });
+    assert_eq!(credentials.gid, unsafe { libc::getgid() });
+  }
+}
+
+#[test]
+fn unix_server_sockets_remove_their_path_on_close() {
+  let path =
+    std::env::temp_dir().join(format!("beam-test-{}.sock", std::process::id()));
+  let _ = std::fs::remove_file(&path);
+  let server = run({
+    let path = path.clone();
+    move || UnixServerSocket::bind(path).unwrap()
+  });
+  assert!(path.exists());
+  server.close().unwrap();
+  assert!(!path.exists());
+  server.close().unwrap();
+  let server = run({
+    let path = path.clone();
+    move || UnixServerSocket::bind(path).unwrap()
+  });
+  assert!(path.exists());
+  drop(server);
+  assert!(!path.exists());
+}
+
+#[test]
+fn socket_reads_commit_into_sliced_buffers() {
+  let (received, base) = run(|| {
+    let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+    sender
+      .send_to(b"hello", receiver.local_address().unwrap())
+      .unwrap();
+    let base = Buffer::from("--head");
+    let mut buffer = base.slice(2..);
+    receiver.receive_from(&mut buffer).unwrap();
+    buffer.shrink_front(4);
+    sender
+      .send_to(b"!", receiver.local_address().unwrap())
+      .unwrap();
+    receiver.receive_from(&mut buffer).unwrap();
+    (buffer, base)
+  });
+  assert_eq!(&received[..], b"hello!");
+  assert_eq!(&base[..], b"--head");
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use beam::routines::*;
+use beam::threading::Mutex;
+use beam::threading::*;
+
+#[test]
+fn routine_mutexes_serialize_routines_across_suspension() {
+  let counter = Arc::new(Mutex::new(0));
+  let routines = (0..32)
+    .map(|_| {
+      let counter = counter.clone();
+      spawn(move || {
+        for _ in 0..100 {
+          let mut value = counter.lock();
+          let current = *value;
+          defer();
+          *value = current + 1;
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  wait_all(&routines);
+  while Arc::strong_count(&counter) != 1 {
+    std::thread::yield_now();
+  }
+  let counter = Arc::into_inner(counter).unwrap();
+  assert_eq!(counter.into_inner(), 3200);
+}
+
+#[test]
+fn routine_mutexes_report_contention_to_try_lock() {
+  let mut mutex = Mutex::new(vec![1]);
+  {
+    let guard = mutex.lock();
+    assert!(mutex.try_lock().is_none());
+    drop(guard);
+  }
+  mutex.try_lock().unwrap().push(2);
+  mutex.get_mut().push(3);
+  assert_eq!(mutex.into_inner(), [1, 2, 3]);
+}
+
+#[test]
+fn syncs_guard_values_with_either_lock() {
+  let value = Arc::new(Sync::<Vec<u32>>::default());
+  let routine_value = Arc::new(Sync::<u32, Mutex<()>>::new(0));
+  let threads = (0..4)
+    .map(|index| {
+      let value = value.clone();
+      let routine_value = routine_value.clone();
+      std::thread::spawn(move || {
+        for _ in 0..250 {
+          value.with_mut(|values| values.push(index));
+          routine_value.with_mut(|count| *count += 1);
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  for thread in threads {
+    thread.join().unwrap();
+  }
+  assert_eq!(value.with(|values| values.len()), 1000);
+  assert_eq!(routine_value.load(), 1000);
+  routine_value.store(7);
+  let mut routine_value = Arc::into_inner(routine_value).unwrap();
+  *routine_value.get_mut() += 1;
+  assert_eq!(routine_value.into_inner(), 8);
+}
+
+#[test]
+fn with_both_locks_in_a_consistent_order() {
+  let first = Arc::new(Sync::<u32>::new(0));
+  let second = Arc::new(Sync::<u32>::new(0));
+  let threads = (0..4)
+    .map(|index| {
+      let first = first.clone();
+      let second = second.clone();
+      std::thread::spawn(move || {
+        for _ in 0..500 {
+          if index % 2 == 0 {
+            with_both(&*first, &*second, |first, second| {
+              *first += 1;
+              *second += 1;
+            });
+          } else {
+            with_both(&*second, &*first, |second, first| {
+              *first += 1;
+              *second += 1;
+            });
+          }
+        }
+      })
+    })
+    .collect::<Vec<_>>();
+  for thread in threads {
+    thread.join().unwrap();
+  }
+  assert_eq!((first.load(), second.load()), (2000, 2000));
+}
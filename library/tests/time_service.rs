@@ -0,0 +1,113 @@
+use std::net::SocketAddr;
+use std::net::UdpSocket;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use beam::time_service::*;
+
+const NTP_UNIX_EPOCH_DELTA: u64 = 2_208_988_800;
+
+fn to_ntp_timestamp(time: SystemTime) -> u64 {
+  let since_epoch = time.duration_since(SystemTime::UNIX_EPOCH).unwrap();
+  let seconds = since_epoch.as_secs() + NTP_UNIX_EPOCH_DELTA;
+  let fraction = ((since_epoch.subsec_nanos() as u64) << 32) / 1_000_000_000;
+  (seconds << 32) | fraction
+}
+
+fn serve<F>(replies: F) -> SocketAddr
+where
+  F: FnOnce(&UdpSocket, SocketAddr, [u8; 48]) + Send + 'static,
+{
+  let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+  let address = socket.local_addr().unwrap();
+  std::thread::spawn(move || {
+    let mut request = [0u8; 48];
+    let (_, peer) = socket.recv_from(&mut request).unwrap();
+    replies(&socket, peer, request);
+  });
+  address
+}
+
+fn response(request: &[u8; 48], offset: Duration) -> [u8; 48] {
+  let mut response = [0u8; 48];
+  response[0] = 0x24;
+  response[24..32].copy_from_slice(&request[40..48]);
+  let timestamp = to_ntp_timestamp(SystemTime::now() + offset).to_be_bytes();
+  response[32..40].copy_from_slice(&timestamp);
+  response[40..48].copy_from_slice(&timestamp);
+  response
+}
+
+#[test]
+fn ntp_clients_measure_the_source_offset() {
+  let source = serve(|socket, peer, request| {
+    let response = response(&request, Duration::from_secs(10));
+    socket.send_to(&response, peer).unwrap();
+  });
+  let client = NtpTimeClient::new(vec![source]);
+  client.synchronize().unwrap();
+  let offset = Duration::from_nanos(client.offset_nanos() as u64);
+  assert!(offset > Duration::from_millis(9_500), "{:?}", offset);
+  assert!(offset < Duration::from_millis(10_500), "{:?}", offset);
+  let skew = client
+    .get_time()
+    .duration_since(SystemTime::now())
+    .unwrap_or_default();
+  assert!(skew > Duration::from_millis(9_500), "{:?}", skew);
+}
+
+#[test]
+fn ntp_clients_time_out_on_silent_sources() {
+  let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+  let client = NtpTimeClient::with_timeout(
+    vec![socket.local_addr().unwrap()],
+    Duration::from_millis(100),
+  );
+  let error = client.synchronize().unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+  assert_eq!(client.offset_nanos(), 0);
+}
+
+#[test]
+fn ntp_clients_ignore_malformed_replies() {
+  let source = serve(|socket, peer, request| {
+    socket.send_to(&[0u8; 12], peer).unwrap();
+    let mut mismatched = response(&request, Duration::from_secs(60));
+    mismatched[24] ^= 0xFF;
+    socket.send_to(&mismatched, peer).unwrap();
+    let response = response(&request, Duration::from_secs(10));
+    socket.send_to(&response, peer).unwrap();
+  });
+  let client = NtpTimeClient::new(vec![source]);
+  client.synchronize().unwrap();
+  let offset = Duration::from_nanos(client.offset_nanos() as u64);
+  assert!(offset < Duration::from_millis(10_500), "{:?}", offset);
+  assert!(offset > Duration::from_millis(9_500), "{:?}", offset);
+}
+
+#[test]
+fn ntp_clients_fail_when_only_malformed_replies_arrive() {
+  let source = serve(|socket, peer, _| {
+    socket.send_to(&[0u8; 12], peer).unwrap();
+    socket.send_to(&[0u8; 48], peer).unwrap();
+  });
+  let client =
+    NtpTimeClient::with_timeout(vec![source], Duration::from_millis(100));
+  let error = client.synchronize().unwrap_err();
+  assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+}
+
+#[test]
+fn incremental_time_clients_advance_on_every_read() {
+  let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+  let client = IncrementalTimeClient::new(start, Duration::from_millis(250));
+  assert_eq!(client.get_time(), start);
+  assert_eq!(client.get_time(), start + Duration::from_millis(250));
+  assert_eq!(client.get_time(), start + Duration::from_millis(500));
+  let default_client = IncrementalTimeClient::default();
+  assert_eq!(default_client.get_time(), SystemTime::UNIX_EPOCH);
+  assert_eq!(
+    default_client.get_time(),
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1)
+  );
+}
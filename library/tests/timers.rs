@@ -0,0 +1,112 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use beam::routines::*;
+use beam::threading::*;
+
+fn wait_for(flag: &AtomicBool) {
+  while !flag.load(Ordering::SeqCst) {
+    std::thread::yield_now();
+  }
+}
+
+fn start_waiter<T: Timer + Clone + 'static>(
+  timer: &T,
+) -> (u64, Arc<Mutex<Option<TimerResult>>>) {
+  let is_started = Arc::new(AtomicBool::new(false));
+  let result = Arc::new(Mutex::new(None));
+  let routine = spawn({
+    let timer = timer.clone();
+    let is_started = is_started.clone();
+    let result = result.clone();
+    move || {
+      is_started.store(true, Ordering::SeqCst);
+      *result.lock().unwrap() = Some(timer.wait());
+    }
+  });
+  wait_for(&is_started);
+  (routine, result)
+}
+
+fn join(waiter: (u64, Arc<Mutex<Option<TimerResult>>>)) -> TimerResult {
+  wait(waiter.0);
+  let result = waiter.1.lock().unwrap().take();
+  result.unwrap()
+}
+
+#[test]
+fn trigger_timers_expire_only_when_triggered() {
+  let timer = TriggerTimer::with_interval(Duration::from_secs(3600));
+  assert_eq!(timer.interval(), Duration::from_secs(3600));
+  assert_eq!(timer.wait(), TimerResult::Canceled);
+  timer.trigger();
+  assert_eq!(timer.wait(), TimerResult::Canceled);
+  timer.start();
+  let waiters = [start_waiter(&timer), start_waiter(&timer)];
+  timer.trigger();
+  for waiter in waiters {
+    assert_eq!(join(waiter), TimerResult::Expired);
+  }
+  assert_eq!(timer.wait(), TimerResult::Expired);
+}
+
+#[test]
+fn trigger_timers_report_cancellation() {
+  let timer = TriggerTimer::new();
+  timer.start();
+  let waiter = start_waiter(&timer);
+  timer.cancel();
+  assert_eq!(join(waiter), TimerResult::Canceled);
+  timer.trigger();
+  assert_eq!(timer.wait(), TimerResult::Canceled);
+  timer.start();
+  timer.trigger();
+  assert_eq!(timer.wait(), TimerResult::Expired);
+}
+
+#[test]
+fn trigger_timer_factories_trigger_every_created_timer() {
+  let factory = TriggerTimerFactory::new();
+  let timers = [
+    factory.create(Duration::from_secs(1)),
+    factory.create(Duration::from_secs(2)),
+  ];
+  for timer in &timers {
+    timer.start();
+  }
+  assert_eq!(factory.timers().len(), 2);
+  assert_eq!(factory.timers()[1].interval(), Duration::from_secs(2));
+  factory.trigger_all();
+  for timer in &timers {
+    assert_eq!(timer.wait(), TimerResult::Expired);
+  }
+}
+
+#[test]
+fn cancelled_live_timers_wake_waiters_without_expiring() {
+  let timer = LiveTimer::new(Duration::from_secs(3600));
+  timer.start();
+  let waiter = start_waiter(&timer);
+  timer.cancel();
+  assert_eq!(join(waiter), TimerResult::Canceled);
+  assert_eq!(timer.wait(), TimerResult::Canceled);
+}
+
+#[test]
+fn cancelled_live_timer_entries_do_not_expire_restarted_timers() {
+  let timer = LiveTimer::new(Duration::ZERO);
+  for _ in 0..100 {
+    timer.start();
+    timer.cancel();
+    assert_eq!(timer.wait(), TimerResult::Canceled);
+  }
+  let long_timer = LiveTimer::new(Duration::from_secs(3600));
+  long_timer.start();
+  timer.start();
+  assert_eq!(timer.wait(), TimerResult::Expired);
+  long_timer.cancel();
+  assert_eq!(long_timer.wait(), TimerResult::Canceled);
+}
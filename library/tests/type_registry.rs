@@ -0,0 +1,88 @@
+use beam::serialization::*;
+
+fn seven() -> u32 {
+  7
+}
+
+#[derive(Debug, Default, PartialEq, Shuttle)]
+#[shuttle(version = 1)]
+struct AccountV1 {
+  id: u32,
+  name: String,
+}
+
+#[derive(Debug, Default, PartialEq, Shuttle)]
+#[shuttle(version = 2)]
+struct AccountV2 {
+  id: u32,
+  name: String,
+  #[shuttle(since = 2, default = "seven")]
+  limit: u32,
+}
+
+#[derive(Debug, Default, PartialEq, Shuttle)]
+struct Order {
+  id: u32,
+}
+
+fn registry<T: Shuttle + 'static>() -> TypeRegistry<dyn Polymorphic> {
+  let mut registry = TypeRegistry::<dyn Polymorphic>::new();
+  registry.register::<T>("account", |value| Box::new(value));
+  registry
+}
+
+fn transfer(
+  sender: &TypeRegistry<dyn Polymorphic>,
+  receiver: &TypeRegistry<dyn Polymorphic>,
+  value: &dyn Polymorphic,
+) -> Box<dyn Polymorphic> {
+  let mut binary_sender = BinarySender::new();
+  sender.send(&mut binary_sender, "message", value).unwrap();
+  let mut binary_receiver = BinaryReceiver::new(binary_sender.data());
+  receiver.receive(&mut binary_receiver, "message").unwrap()
+}
+
+#[test]
+fn registries_with_different_versions_agree() {
+  let old = registry::<AccountV1>();
+  let new = registry::<AccountV2>();
+  assert!(old.verify(&new.manifest()).is_ok());
+  assert!(new.verify(&old.manifest()).is_ok());
+  assert_eq!(old.fingerprint(), new.fingerprint());
+  let account = AccountV1 {
+    id: 1,
+    name: String::from("a"),
+  };
+  let received = transfer(&old, &new, &account);
+  assert_eq!(
+    (*received).as_any().downcast_ref::<AccountV2>(),
+    Some(&AccountV2 {
+      id: 1,
+      name: String::from("a"),
+      limit: 7,
+    })
+  );
+  let account = AccountV2 {
+    id: 2,
+    name: String::from("b"),
+    limit: 3,
+  };
+  let received = transfer(&new, &old, &account);
+  assert_eq!(
+    (*received).as_any().downcast_ref::<AccountV1>(),
+    Some(&AccountV1 {
+      id: 2,
+      name: String::from("b"),
+    })
+  );
+}
+
+#[test]
+fn registries_with_different_types_disagree() {
+  let local = registry::<AccountV1>();
+  let mut remote = registry::<AccountV1>();
+  remote.register::<Order>("order", |value| Box::new(value));
+  assert!(local.verify(&remote.manifest()).is_err());
+  assert!(remote.verify(&local.manifest()).is_err());
+  assert_ne!(local.fingerprint(), remote.fingerprint());
+}